@@ -0,0 +1,296 @@
+use crate::model::building::BuildingId;
+use crate::model::character::gender::Gender;
+use crate::model::character::gender::Gender::Male;
+use crate::model::character::relation::building::BuildingRelationType::Builder;
+use crate::model::character::relation::character::family::RelativeType::{Child, Parent, Sibling};
+use crate::model::character::relation::character::CharacterRelationType::{
+    Guardian, Relative, Spouse,
+};
+use crate::model::character::{Character, CharacterId, CharacterMgr};
+use crate::model::town::TownId;
+use crate::usecase::building::get_building_relation;
+use crate::usecase::building::occupancy::get_building_occupied_by;
+use crate::usecase::character::relation::get::{
+    get_children, get_guardians, get_parents, get_siblings, get_spouses,
+};
+use crate::world::World;
+use std::collections::HashSet;
+
+pub fn get_biography(world: &World, id: CharacterId) -> String {
+    let manager = &world.data().character_manager;
+    let character = manager.get(id).unwrap();
+    let gender = *character.gender();
+    let pronoun = get_pronoun(gender);
+    let is = if character.is_alive() { "is" } else { "was" };
+    let mut sentences = vec![show_birth(manager, character)];
+
+    let guardians = sort_by_birth(manager, get_guardians(manager, id));
+    if !guardians.is_empty() {
+        sentences.push(format!(
+            "{} was raised by {} {} {}.",
+            pronoun,
+            get_possessive(gender),
+            Guardian.get_gender_specific_string(gender),
+            join(show_names(&guardians)),
+        ));
+    }
+
+    for (relatives, relation) in [
+        (get_siblings(manager, id), Relative(Sibling)),
+        (get_spouses(manager, id), Spouse),
+        (get_children(manager, id), Relative(Parent)),
+    ] {
+        let relatives = sort_by_birth(manager, relatives);
+
+        if !relatives.is_empty() {
+            sentences.push(format!(
+                "{} {} the {} of {}.",
+                pronoun,
+                is,
+                relation.get_gender_specific_string(gender),
+                join(show_names(&relatives)),
+            ));
+        }
+    }
+
+    let buildings: Vec<String> = get_building_relation(manager, id, Builder)
+        .into_iter()
+        .map(|building| show_building(world, *character.town(), building))
+        .collect();
+    if !buildings.is_empty() {
+        sentences.push(format!("{} built {}.", pronoun, join(buildings)));
+    }
+
+    sentences.push(show_end(world, character));
+
+    sentences.join(" ")
+}
+
+pub fn to_markdown(world: &World, id: CharacterId) -> String {
+    let character = world.data().character_manager.get(id).unwrap();
+
+    format!("# {}\n\n{}\n", character.name(), get_biography(world, id))
+}
+
+fn show_birth(manager: &CharacterMgr, character: &Character) -> String {
+    let mut parents = sort_by_birth(manager, get_parents(manager, *character.id()));
+    parents.sort_by_key(|parent| *parent.gender() != Male);
+
+    let mut text = format!(
+        "{} was born in {}",
+        character.name(),
+        character.birth_date().get_year()
+    );
+
+    if !parents.is_empty() {
+        let names = parents
+            .iter()
+            .map(|parent| parent.name().get_first().to_string())
+            .collect();
+        text.push_str(&format!(" to {}", join(names)));
+    }
+
+    text.push('.');
+    text
+}
+
+fn show_end(world: &World, character: &Character) -> String {
+    let manager = &world.data().character_manager;
+    let gender = *character.gender();
+    let pronoun = get_pronoun(gender);
+    let town = world.get_name(*character.town());
+
+    if let Some(date) = character.death_date() {
+        let id = *character.id();
+        let survivors: Vec<String> = sort_by_birth(manager, get_spouses(manager, id))
+            .into_iter()
+            .map(|spouse| (spouse, Spouse))
+            .chain(
+                sort_by_birth(manager, get_children(manager, id))
+                    .into_iter()
+                    .map(|child| (child, Relative(Child))),
+            )
+            .filter(|(survivor, _)| survivor.is_alive())
+            .map(|(survivor, relation)| {
+                format!(
+                    "{} {} {}",
+                    get_possessive(gender),
+                    relation.get_gender_specific_string(*survivor.gender()),
+                    survivor.name()
+                )
+            })
+            .collect();
+
+        format!(
+            "{} died in {} at the age of {}{}.",
+            pronoun,
+            date.get_year(),
+            character.get_age(*date),
+            if survivors.is_empty() {
+                String::new()
+            } else {
+                format!(", survived by {}", join(survivors))
+            }
+        )
+    } else if let Some(date) = character.emigration_date() {
+        format!("{} left {} in {}.", pronoun, town, date.get_year())
+    } else {
+        format!(
+            "{} is {} years old and lives in {}{}.",
+            pronoun,
+            character.get_age(world.data().date),
+            get_building_occupied_by(manager, *character.id())
+                .map(|home| format!("{} in ", show_building(world, *character.town(), home)))
+                .unwrap_or_default(),
+            town
+        )
+    }
+}
+
+/// Buildings without an address were demolished.
+fn show_building(world: &World, town: TownId, id: BuildingId) -> String {
+    let usage = world
+        .get_building_manager(town)
+        .get(id)
+        .map(|building| building.usage().to_string().to_lowercase())
+        .unwrap_or_else(|| "building".to_string());
+    let map = world.get_map(town);
+
+    if let Some((block, lot)) = map.find_building(id) {
+        format!("the {} at {}", usage, map.get_address(block, lot))
+    } else {
+        format!("a {}, which no longer stands", usage)
+    }
+}
+
+fn sort_by_birth(manager: &CharacterMgr, ids: HashSet<CharacterId>) -> Vec<&Character> {
+    let mut characters: Vec<&Character> =
+        ids.into_iter().map(|id| manager.get(id).unwrap()).collect();
+    characters.sort_by_key(|character| (character.birth_date().get_year(), character.id().id()));
+    characters
+}
+
+fn show_names(characters: &[&Character]) -> Vec<String> {
+    characters
+        .iter()
+        .map(|character| character.name().to_string())
+        .collect()
+}
+
+fn get_pronoun(gender: Gender) -> &'static str {
+    if gender == Male {
+        "He"
+    } else {
+        "She"
+    }
+}
+
+fn get_possessive(gender: Gender) -> &'static str {
+    if gender == Male {
+        "his"
+    } else {
+        "her"
+    }
+}
+
+/// Joins the parts like "a, b and c".
+fn join(mut parts: Vec<String>) -> String {
+    match parts.pop() {
+        None => String::new(),
+        Some(last) if parts.is_empty() => last,
+        Some(last) => format!("{} and {}", parts.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::character::gender::Gender::Female;
+    use crate::model::character::name::CharacterName;
+    use crate::model::time::Date;
+    use crate::model::town::map::TownMap;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::usecase::character::birth::birth_with_relations;
+    use crate::usecase::character::death::death;
+    use crate::usecase::character::marriage::wed;
+    use crate::usecase::character::{set_gender, set_name};
+    use crate::SimulationData;
+
+    fn create_world() -> (World, [CharacterId; 4]) {
+        let mut data = SimulationData {
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let father = data.character_manager.create();
+        let mother = data.character_manager.create();
+        wed(&mut data, father, mother);
+        let manager = &mut data.character_manager;
+        let son = birth_with_relations(manager, father, mother);
+        let daughter = birth_with_relations(manager, father, mother);
+
+        for (id, first, last, gender, year) in [
+            (father, "John", "Smith", Male, 1790),
+            (mother, "Mary", "Smith", Female, 1792),
+            (son, "Robert", "Smith", Male, 1820),
+            (daughter, "Anna", "Smith", Female, 1822),
+        ] {
+            set_name(manager, id, CharacterName::standard(first, last));
+            set_gender(manager, id, gender);
+            manager.get_mut(id).unwrap().set_birth_date(Date::new(year));
+        }
+
+        data.date = Date::new(1845);
+        let house = build(&mut data, 4, 0, BuildingUsage::house(), son, son);
+        relocate_to_house(&mut data, vec![son], house);
+        data.date = Date::new(1850);
+        death(&mut data, father);
+
+        (World::new(0, "Town", data), [father, mother, son, daughter])
+    }
+
+    #[test]
+    fn biography_of_dead_character() {
+        let (world, [father, ..]) = create_world();
+
+        assert_eq!(
+            get_biography(&world, father),
+            "John Smith was born in 1790. \
+             He was the husband of Mary Smith. \
+             He was the father of Robert Smith and Anna Smith. \
+             He died in 1850 at the age of 60, \
+             survived by his wife Mary Smith, his son Robert Smith and his daughter Anna Smith."
+        );
+    }
+
+    #[test]
+    fn biography_of_living_character() {
+        let (world, [_, _, son, daughter]) = create_world();
+
+        assert_eq!(
+            get_biography(&world, son),
+            "Robert Smith was born in 1820 to John and Mary. \
+             He is the brother of Anna Smith. \
+             He built the house at 6 Street 2. \
+             He is 30 years old and lives in the house at 6 Street 2 in Town."
+        );
+        assert_eq!(
+            to_markdown(&world, daughter),
+            "# Anna Smith\n\n\
+             Anna Smith was born in 1822 to John and Mary. \
+             She is the sister of Robert Smith. \
+             She is 28 years old and lives in Town.\n"
+        );
+    }
+
+    #[test]
+    fn test_join() {
+        let parts = |parts: &[&str]| parts.iter().map(|part| part.to_string()).collect();
+
+        assert_eq!(join(parts(&[])), "");
+        assert_eq!(join(parts(&["a"])), "a");
+        assert_eq!(join(parts(&["a", "b"])), "a and b");
+        assert_eq!(join(parts(&["a", "b", "c"])), "a, b and c");
+    }
+}
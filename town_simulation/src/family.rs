@@ -0,0 +1,291 @@
+use crate::model::building::BuildingId;
+use crate::model::character::relation::building::BuildingRelationType::Builder;
+use crate::model::character::{Character, CharacterId};
+use crate::model::town::TownId;
+use crate::usecase::building::get_building_relation;
+use crate::usecase::building::ownership::get_buildings_owned_by;
+use crate::usecase::character::wealth::get_wealth_in_town;
+use crate::world::World;
+use derive_getters::Getters;
+use std::collections::BTreeSet;
+
+/// The characters, who were born with or married into a last name.
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct Family {
+    name: String,
+    /// Sorted by birth date.
+    members: Vec<CharacterId>,
+    living_members: usize,
+    /// The oldest member born with the name.
+    founder: Option<CharacterId>,
+    /// The oldest living resident, who was born with & still has the name.
+    /// Otherwise the oldest living resident born with the name or else the oldest living resident.
+    head: Option<CharacterId>,
+    /// The buildings owned by the living members.
+    property: Vec<(TownId, BuildingId)>,
+    wealth: u32,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FamilyEvent {
+    Birth(CharacterId),
+    Death(CharacterId),
+    Build {
+        builder: CharacterId,
+        town: TownId,
+        building: BuildingId,
+    },
+}
+
+pub fn is_member(character: &Character, name: &str) -> bool {
+    character.name().get_last() == Some(name) || is_born_member(character, name)
+}
+
+fn is_born_member(character: &Character, name: &str) -> bool {
+    character.name().get_birth_last() == Some(name)
+}
+
+pub fn get_family(world: &World, name: &str) -> Option<Family> {
+    let data = world.data();
+    let mut members: Vec<&Character> = data
+        .character_manager
+        .get_all()
+        .iter()
+        .filter(|character| is_member(character, name))
+        .collect();
+
+    if members.is_empty() {
+        return None;
+    }
+
+    members.sort_by_key(|character| (character.birth_date().get_year(), character.id().id()));
+
+    let living: Vec<&Character> = members
+        .iter()
+        .copied()
+        .filter(|character| character.is_resident())
+        .collect();
+    let property: BTreeSet<(usize, usize)> = living
+        .iter()
+        .flat_map(|character| {
+            get_buildings_owned_by(&data.character_manager, *character.id())
+                .into_iter()
+                .map(|building| (character.town().id(), building.id()))
+        })
+        .collect();
+    let wealth = living
+        .iter()
+        .map(|character| {
+            let town = *character.town();
+            get_wealth_in_town(
+                &data.character_manager,
+                world.get_building_manager(town),
+                world.get_map(town),
+                *character.id(),
+            )
+        })
+        .sum();
+
+    Some(Family {
+        name: name.to_string(),
+        members: members.iter().map(|character| *character.id()).collect(),
+        living_members: living.len(),
+        founder: members
+            .iter()
+            .find(|character| is_born_member(character, name))
+            .map(|character| *character.id()),
+        head: living
+            .iter()
+            .find(|character| {
+                is_born_member(character, name) && character.name().get_last() == Some(name)
+            })
+            .or_else(|| {
+                living
+                    .iter()
+                    .find(|character| is_born_member(character, name))
+            })
+            .or(living.first())
+            .map(|character| *character.id()),
+        property: property
+            .into_iter()
+            .map(|(town, building)| (TownId::new(town), BuildingId::new(building)))
+            .collect(),
+        wealth,
+    })
+}
+
+/// Returns all families sorted by the number of living members, their wealth & their name.
+pub fn get_families(world: &World) -> Vec<Family> {
+    let names: BTreeSet<&str> = world
+        .data()
+        .character_manager
+        .get_all()
+        .iter()
+        .flat_map(|character| {
+            let name = character.name();
+            name.get_last().into_iter().chain(name.get_birth_last())
+        })
+        .collect();
+    let mut families: Vec<Family> = names
+        .into_iter()
+        .filter_map(|name| get_family(world, name))
+        .collect();
+
+    families.sort_by(|a, b| {
+        b.living_members
+            .cmp(&a.living_members)
+            .then(b.wealth.cmp(&a.wealth))
+            .then(a.name.cmp(&b.name))
+    });
+
+    families
+}
+
+pub fn get_timeline(world: &World, family: &Family) -> Vec<(u32, FamilyEvent)> {
+    let manager = &world.data().character_manager;
+    let mut timeline = Vec::new();
+
+    for id in &family.members {
+        let character = manager.get(*id).unwrap();
+        let town = *character.town();
+
+        timeline.push((character.birth_date().get_year(), FamilyEvent::Birth(*id)));
+
+        if let Some(date) = character.death_date() {
+            timeline.push((date.get_year(), FamilyEvent::Death(*id)));
+        }
+
+        for building in get_building_relation(manager, *id, Builder) {
+            if let Some(date) = world
+                .get_building_manager(town)
+                .get(building)
+                .map(|building| *building.construction_date())
+            {
+                timeline.push((
+                    date.get_year(),
+                    FamilyEvent::Build {
+                        builder: *id,
+                        town,
+                        building,
+                    },
+                ));
+            }
+        }
+    }
+
+    timeline.sort_by_key(|(year, event)| {
+        (
+            *year,
+            match event {
+                FamilyEvent::Birth(id) => (0, id.id(), 0),
+                FamilyEvent::Build {
+                    builder, building, ..
+                } => (1, builder.id(), building.id()),
+                FamilyEvent::Death(id) => (2, id.id(), 0),
+            },
+        )
+    });
+
+    timeline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::owner::Owner;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::character::name::CharacterName;
+    use crate::model::time::Date;
+    use crate::model::town::map::TownMap;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::ownership::update_owner;
+    use crate::usecase::character::death::death;
+    use crate::usecase::character::set_name;
+    use crate::SimulationData;
+
+    fn create_world() -> (World, [CharacterId; 4]) {
+        let mut data = SimulationData {
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let manager = &mut data.character_manager;
+        let founder = manager.create();
+        let wife = manager.create();
+        let son = manager.create();
+        let other = manager.create();
+
+        set_name(manager, founder, CharacterName::standard("A", "Smith"));
+        set_name(
+            manager,
+            wife,
+            CharacterName::married("B", "Smith", "Miller"),
+        );
+        set_name(manager, son, CharacterName::standard("C", "Smith"));
+        set_name(manager, other, CharacterName::standard("D", "Jones"));
+
+        for (id, year) in [(founder, 0), (wife, 2), (son, 20), (other, 1)] {
+            manager.get_mut(id).unwrap().set_birth_date(Date::new(year));
+        }
+
+        data.date = Date::new(30);
+        let house = build(&mut data, 4, 0, BuildingUsage::house(), founder, founder);
+        data.date = Date::new(40);
+        death(&mut data, founder);
+        update_owner(&mut data, house, Owner::Character(son));
+
+        (World::new(0, "Town", data), [founder, wife, son, other])
+    }
+
+    #[test]
+    fn family_members_founder_head_and_property() {
+        let (world, [founder, wife, son, _]) = create_world();
+        let family = get_family(&world, "Smith").unwrap();
+
+        assert_eq!(family.members, vec![founder, wife, son]);
+        assert_eq!(family.living_members, 2);
+        assert_eq!(family.founder, Some(founder));
+        assert_eq!(family.head, Some(son));
+        assert_eq!(
+            family.property,
+            vec![(TownId::default(), BuildingId::new(0))]
+        );
+        assert_eq!(family.wealth, 100);
+        assert!(get_family(&world, "Unknown").is_none());
+    }
+
+    #[test]
+    fn families_are_ranked_by_size() {
+        let (world, [_, wife, _, _]) = create_world();
+        let names: Vec<String> = get_families(&world)
+            .into_iter()
+            .map(|family| family.name)
+            .collect();
+
+        assert_eq!(names, vec!["Smith", "Jones", "Miller"]);
+        assert_eq!(get_family(&world, "Miller").unwrap().members, vec![wife]);
+    }
+
+    #[test]
+    fn timeline_of_family() {
+        let (world, [founder, wife, son, _]) = create_world();
+        let family = get_family(&world, "Smith").unwrap();
+
+        assert_eq!(
+            get_timeline(&world, &family),
+            vec![
+                (0, FamilyEvent::Birth(founder)),
+                (2, FamilyEvent::Birth(wife)),
+                (20, FamilyEvent::Birth(son)),
+                (
+                    30,
+                    FamilyEvent::Build {
+                        builder: founder,
+                        town: TownId::default(),
+                        building: BuildingId::new(0),
+                    }
+                ),
+                (40, FamilyEvent::Death(founder)),
+            ]
+        );
+    }
+}
@@ -4,7 +4,7 @@ use crate::model::character::name::CharacterName;
 use crate::model::character::{CharacterId, CharacterMgr};
 use crate::usecase::character::relation::get::get_parents;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct CharacterNameGenerator {
     male_names: NameGenerator,
     female_names: NameGenerator,
@@ -55,10 +55,8 @@ impl CharacterNameGenerator {
     fn generate_family<'a>(&'a self, manager: &'a CharacterMgr, id: CharacterId) -> &'a str {
         get_parents(manager, id)
             .iter()
-            .map(|parent_id| manager.get(*parent_id))
-            .flatten()
-            .map(|parent| parent.name().get_last())
-            .flatten()
+            .filter_map(|parent_id| manager.get(*parent_id))
+            .filter_map(|parent| parent.name().get_last())
             .next()
             .unwrap_or_else(|| self.generate_name(&self.family_names, id))
     }
@@ -4,14 +4,15 @@ use serde::Deserialize;
 use std::io;
 
 pub mod character;
+pub mod street;
 
-#[derive(Constructor, Debug, Deserialize)]
+#[derive(Constructor, Clone, Debug, Deserialize)]
 pub struct Entry {
     name: String,
     value: u32,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NameGenerator {
     entries: Vec<Entry>,
     total_value: u32,
@@ -59,6 +60,14 @@ impl NameGenerator {
         Self::new(entries)
     }
 
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
     pub fn get(&self, index: u32) -> &str {
         let index = index % self.total_value;
 
@@ -0,0 +1,66 @@
+use crate::generation::name::NameGenerator;
+use std::collections::HashSet;
+
+#[derive(Clone, Default, Debug)]
+pub struct StreetNameGenerator {
+    names: NameGenerator,
+}
+
+impl StreetNameGenerator {
+    pub fn new(names: NameGenerator) -> Self {
+        Self { names }
+    }
+
+    pub fn load(setting: &str) -> Self {
+        Self::new(NameGenerator::read(&format!("{}-street.csv", setting)))
+    }
+
+    /// Generates a name, which is not used yet.
+    pub fn generate(&self, index: usize, suffix: &str, used: &HashSet<String>) -> String {
+        let mut offset = 0;
+
+        loop {
+            let name = format!("{} {}", self.names.get((index + offset) as u32), suffix);
+
+            if !used.contains(&name) {
+                return name;
+            }
+
+            offset += 1;
+
+            if offset > self.names.len() {
+                return format!("{} {} {}", self.names.get(index as u32), suffix, index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let generator = StreetNameGenerator::default();
+        let mut used = HashSet::new();
+
+        assert_eq!(generator.generate(0, "Street", &used), "A Street");
+        assert_eq!(generator.generate(1, "Road", &used), "B Road");
+
+        used.insert("A Street".to_string());
+        used.insert("B Street".to_string());
+
+        assert_eq!(generator.generate(0, "Street", &used), "C Street");
+    }
+
+    #[test]
+    fn add_index_if_all_names_are_used() {
+        let generator = StreetNameGenerator::default();
+        let used = ["A Street", "B Street", "C Street"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        assert_eq!(generator.generate(4, "Street", &used), "B Street 4");
+    }
+}
@@ -6,6 +6,8 @@ use std::hash::Hasher;
 #[derive(Clone, Debug, PartialEq)]
 pub enum RandomNumberGenerator {
     Hash,
+    /// Like [`RandomNumberGenerator::Hash`], but different seeds generate different numbers.
+    Seeded(u64),
     Mock {
         values: HashMap<(u32, usize, u32), u64>,
         default: u64,
@@ -17,6 +19,11 @@ impl RandomNumberGenerator {
         (self.next(year, index, usage) % 100) as u32
     }
 
+    /// Picks an index of a non-empty list with the given length.
+    pub fn roll_index(&self, year: u32, index: usize, usage: u32, length: usize) -> usize {
+        (self.next(year, index, usage) % length as u64) as usize
+    }
+
     fn next(&self, year: u32, index: usize, usage: u32) -> u64 {
         match self {
             RandomNumberGenerator::Hash => {
@@ -26,9 +33,34 @@ impl RandomNumberGenerator {
                 hasher.write_u32(usage);
                 hasher.finish()
             }
+            RandomNumberGenerator::Seeded(seed) => {
+                let mut hasher = DefaultHasher::new();
+                hasher.write_u64(*seed);
+                hasher.write_u32(year);
+                hasher.write_usize(index);
+                hasher.write_u32(usage);
+                hasher.finish()
+            }
             RandomNumberGenerator::Mock { values, default } => {
                 *values.get(&(year, index, usage)).unwrap_or(default)
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_numbers_depend_on_seed() {
+        let rng0 = RandomNumberGenerator::Seeded(0);
+        let rng1 = RandomNumberGenerator::Seeded(1);
+        let rolls = |rng: &RandomNumberGenerator| -> Vec<u32> {
+            (0..10).map(|index| rng.roll_d100(0, index, 0)).collect()
+        };
+
+        assert_eq!(rolls(&rng0), rolls(&RandomNumberGenerator::Seeded(0)));
+        assert_ne!(rolls(&rng0), rolls(&rng1));
+    }
+}
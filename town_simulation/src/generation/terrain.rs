@@ -0,0 +1,181 @@
+use crate::generation::number::RandomNumberGenerator;
+use crate::model::town::map::TownBlock::{EmptyBlock, Farmland, Forest, Hills, Mill, Water};
+use crate::model::town::map::TownMap;
+
+const COAST: u32 = 1;
+const RIVER: u32 = 2;
+const NATURE: u32 = 3;
+
+/// Generates a coast, a river with a mill, hills, forests & farms on an empty map.
+/// The same seed of the random number generator always generates the same terrain.
+pub fn generate_terrain(map: &mut TownMap, rng: &RandomNumberGenerator) {
+    generate_coast(map, rng);
+    let river = generate_river(map, rng);
+    generate_mill(map, &river);
+    generate_nature(map, rng);
+}
+
+fn generate_coast(map: &mut TownMap, rng: &RandomNumberGenerator) {
+    let chance_of_coast = 40;
+    let roll = rng.roll_d100(0, 0, COAST);
+
+    if roll >= chance_of_coast {
+        return;
+    }
+
+    let (width, height) = (*map.width(), *map.height());
+    let blocks: Vec<usize> = match roll % 4 {
+        0 => (0..width).map(|column| map.get_index(column, 0)).collect(),
+        1 => (0..height)
+            .map(|row| map.get_index(width - 1, row))
+            .collect(),
+        2 => (0..width)
+            .map(|column| map.get_index(column, height - 1))
+            .collect(),
+        _ => (0..height).map(|row| map.get_index(0, row)).collect(),
+    };
+
+    for block in blocks {
+        set_water(map, block);
+    }
+}
+
+/// The river flows from north to south & bends randomly. Returns its blocks.
+fn generate_river(map: &mut TownMap, rng: &RandomNumberGenerator) -> Vec<usize> {
+    let chance_of_river = 70;
+    let width = *map.width();
+
+    if rng.roll_d100(0, 1, RIVER) >= chance_of_river {
+        return Vec::new();
+    }
+
+    let mut column = rng.roll_d100(0, 2, RIVER) as usize % width;
+    let mut river = Vec::new();
+
+    for row in 0..*map.height() {
+        let block = map.get_index(column, row);
+
+        if set_water(map, block) {
+            river.push(block);
+        }
+
+        column = match rng.roll_d100(0, 3 + row, RIVER) % 3 {
+            0 => column.saturating_sub(1),
+            1 => (column + 1).min(width - 1),
+            _ => column,
+        };
+    }
+
+    river
+}
+
+/// The centre of the town never becomes water.
+fn set_water(map: &mut TownMap, block: usize) -> bool {
+    if map.get_distance_to_centre(block) > 0 && map.blocks()[block] == EmptyBlock {
+        map.set_block(block, Water);
+        return true;
+    }
+
+    false
+}
+
+/// The mill is built on the first free block next to the river, but outside the centre.
+fn generate_mill(map: &mut TownMap, river: &[usize]) {
+    let min_distance = 2;
+
+    let site = river
+        .iter()
+        .flat_map(|block| map.get_neighbours(*block))
+        .find(|block| {
+            map.blocks()[*block] == EmptyBlock && map.get_distance_to_centre(*block) >= min_distance
+        });
+
+    if let Some(block) = site {
+        map.set_block(block, Mill);
+    }
+}
+
+/// Forests & hills surround the town, while farms are on the plains further away.
+fn generate_nature(map: &mut TownMap, rng: &RandomNumberGenerator) {
+    let min_distance = 3;
+    let min_farm_distance = 4;
+    let chance_of_forest = 15;
+    let chance_of_hills = 25;
+    let chance_of_farm = 45;
+
+    for block in 0..map.blocks().len() {
+        let distance = map.get_distance_to_centre(block);
+
+        if distance < min_distance || map.blocks()[block] != EmptyBlock {
+            continue;
+        }
+
+        let roll = rng.roll_d100(0, block, NATURE);
+
+        if roll < chance_of_forest {
+            map.set_block(block, Forest);
+        } else if roll < chance_of_hills {
+            map.set_block(block, Hills);
+        } else if distance >= min_farm_distance && roll < chance_of_farm {
+            map.set_block(block, Farmland);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::town::map::TownBlock;
+    use std::collections::HashMap;
+
+    #[test]
+    fn same_seed_generates_same_terrain() {
+        let mut map0 = TownMap::empty(9, 9);
+        let mut map1 = TownMap::empty(9, 9);
+
+        generate_terrain(&mut map0, &RandomNumberGenerator::Seeded(7));
+        generate_terrain(&mut map1, &RandomNumberGenerator::Seeded(7));
+
+        assert_eq!(map0, map1);
+        assert_eq!(map0.blocks()[map0.get_centre_block()], EmptyBlock);
+    }
+
+    #[test]
+    fn generate_river_with_mill() {
+        let rng = RandomNumberGenerator::Mock {
+            values: HashMap::from([((0, 1, RIVER), 0), ((0, 2, RIVER), 0)]),
+            default: 99,
+        };
+        let mut map = TownMap::empty(5, 5);
+
+        generate_terrain(&mut map, &rng);
+
+        for row in 0..5 {
+            assert_eq!(map.get_block(row, 0), &Water);
+        }
+        assert_eq!(map.get_block(0, 1), &Mill);
+        assert_eq!(
+            map.blocks()
+                .iter()
+                .filter(|block| **block == TownBlock::EmptyBlock)
+                .count(),
+            19
+        );
+    }
+
+    #[test]
+    fn generate_coast_and_nature() {
+        let rng = RandomNumberGenerator::Mock {
+            values: HashMap::from([((0, 0, COAST), 2), ((0, 0, NATURE), 10)]),
+            default: 99,
+        };
+        let mut map = TownMap::empty(5, 5);
+
+        generate_terrain(&mut map, &rng);
+
+        for column in 0..5 {
+            assert_eq!(map.get_block(4, column), &Water);
+        }
+        assert_eq!(map.get_block(0, 0), &Forest);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::world::World;
+use std::collections::BTreeMap;
+
+/// Every snapshot of the most recent years is kept.
+const RECENT_YEARS: u32 = 20;
+/// Older snapshots are only kept for every n-th year to limit the memory usage.
+const OLDER_INTERVAL: u32 = 10;
+
+/// Snapshots of the world at the start of simulated years, so the simulation can be rewound.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    snapshots: BTreeMap<u32, World>,
+}
+
+impl History {
+    /// Stores a snapshot of the world, which replaces an older one of the same year.
+    /// Snapshots older than the recent years are thinned out.
+    pub fn record(&mut self, world: &World) {
+        let year = world.data().date.get_year();
+        self.snapshots.insert(year, world.clone());
+
+        let recent = year.saturating_sub(RECENT_YEARS);
+        self.snapshots.retain(|snapshot_year, _| {
+            *snapshot_year >= recent || *snapshot_year % OLDER_INTERVAL == 0
+        });
+    }
+
+    pub fn get(&self, year: u32) -> Option<&World> {
+        self.snapshots.get(&year)
+    }
+
+    pub fn get_years(&self) -> Vec<u32> {
+        self.snapshots.keys().copied().collect()
+    }
+
+    /// Returns the world at the start of the year & forgets all later snapshots,
+    /// because the simulation branches off from there.
+    pub fn rewind(&mut self, year: u32) -> Option<World> {
+        let world = self.snapshots.get(&year)?.clone();
+
+        self.snapshots
+            .retain(|snapshot_year, _| *snapshot_year <= year);
+
+        Some(world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::time::Date;
+    use crate::SimulationData;
+
+    fn create_world(year: u32, characters: usize) -> World {
+        let mut data = SimulationData {
+            date: Date::new(year),
+            ..SimulationData::default()
+        };
+
+        for _ in 0..characters {
+            data.character_manager.create();
+        }
+
+        World::new(0, "Town", data)
+    }
+
+    #[test]
+    fn rewind_to_snapshot() {
+        let mut history = History::default();
+
+        history.record(&create_world(10, 1));
+        history.record(&create_world(11, 2));
+        history.record(&create_world(12, 3));
+
+        assert_eq!(history.get_years(), vec![10, 11, 12]);
+
+        let world = history.rewind(11).unwrap();
+
+        assert_eq!(world.data().date, Date::new(11));
+        assert_eq!(world.data().character_manager.get_all().len(), 2);
+        assert_eq!(history.get_years(), vec![10, 11]);
+    }
+
+    #[test]
+    fn older_snapshots_are_thinned_out() {
+        let mut history = History::default();
+
+        for year in 0..=45 {
+            history.record(&create_world(year, 0));
+        }
+
+        assert_eq!(
+            history.get_years(),
+            vec![0, 10, 20]
+                .into_iter()
+                .chain(25..=45)
+                .collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn rewind_to_unknown_year() {
+        let mut history = History::default();
+
+        history.record(&create_world(10, 1));
+
+        assert!(history.rewind(9).is_none());
+        assert_eq!(history.get_years(), vec![10]);
+    }
+}
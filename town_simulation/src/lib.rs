@@ -1,22 +1,37 @@
 extern crate derive_more;
 
 use crate::generation::name::character::CharacterNameGenerator;
+use crate::generation::name::street::StreetNameGenerator;
 use crate::model::building::BuildingMgr;
 use crate::model::character::CharacterMgr;
+use crate::model::event::EventRecord;
+use crate::model::inheritance::InheritanceLaw;
 use crate::model::time::Date;
 use crate::model::town::map::TownMap;
+use crate::model::town::TownId;
 
+pub mod biography;
+pub mod family;
 pub mod generation;
+pub mod history;
 pub mod model;
 pub mod simulation;
+pub mod statistics;
 pub mod usecase;
 pub mod util;
+pub mod world;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct SimulationData {
     pub building_manager: BuildingMgr,
     pub character_manager: CharacterMgr,
     pub character_name_generator: CharacterNameGenerator,
     pub date: Date,
+    pub inheritance_law: InheritanceLaw,
     pub map: TownMap,
+    pub street_name_generator: StreetNameGenerator,
+    /// The town, whose map & buildings are simulated.
+    pub town: TownId,
+    /// The events, which weren't dispatched to the observers yet.
+    pub events: Vec<EventRecord>,
 }
@@ -1,11 +1,16 @@
+use crate::model::building::owner::Owner;
 use crate::model::building::usage::BuildingUsage;
 use crate::model::character::CharacterId;
 use crate::model::time::Date;
 use derive_getters::Getters;
 use derive_more::Constructor;
 
+pub mod owner;
 pub mod usage;
 
+/// The condition of a new or renovated building.
+pub const MAX_CONDITION: u32 = 100;
+
 #[derive(Constructor, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct BuildingId(usize);
 
@@ -15,41 +20,109 @@ impl BuildingId {
     }
 }
 
-#[derive(Constructor, Getters, Clone, Debug, PartialEq)]
+#[derive(Getters, Clone, Debug, PartialEq)]
 pub struct Building {
     id: BuildingId,
     usage: BuildingUsage,
     construction_date: Date,
+    /// Is none while the building is under construction
+    completion_date: Option<Date>,
     builder: CharacterId,
-    owner: CharacterId,
-    previous_owners: Vec<CharacterId>,
+    workers: Vec<CharacterId>,
+    owner: Owner,
+    previous_owners: Vec<Owner>,
+    /// Decreases over time & the building collapses at 0
+    condition: u32,
+    renovation_dates: Vec<Date>,
+    /// Demolished buildings are kept as historical records
+    demolition_date: Option<Date>,
 }
 
 impl Building {
+    pub fn new(
+        id: BuildingId,
+        usage: BuildingUsage,
+        construction_date: Date,
+        builder: CharacterId,
+        owner: Owner,
+        previous_owners: Vec<Owner>,
+    ) -> Self {
+        Self {
+            id,
+            usage,
+            construction_date,
+            completion_date: Some(construction_date),
+            builder,
+            workers: Vec::new(),
+            owner,
+            previous_owners,
+            condition: MAX_CONDITION,
+            renovation_dates: Vec::new(),
+            demolition_date: None,
+        }
+    }
+
     pub fn get_age(&self, date: Date) -> u32 {
         date.get_years_since(self.construction_date)
     }
 
+    pub fn is_under_construction(&self) -> bool {
+        self.completion_date.is_none()
+    }
+
+    pub fn is_construction_finished(&self, date: Date) -> bool {
+        self.get_age(date) >= self.usage.get_construction_time()
+    }
+
+    pub fn start_construction(&mut self, workers: Vec<CharacterId>) {
+        self.completion_date = None;
+        self.workers = workers;
+    }
+
+    pub fn complete_construction(&mut self, date: Date) {
+        if !self.is_under_construction() {
+            panic!("Building {} is already completed!", self.id.0);
+        }
+
+        self.completion_date = Some(date);
+    }
+
     pub fn get_usage_mut(&mut self) -> &mut BuildingUsage {
         &mut self.usage
     }
 
     pub fn remove_occupant(&mut self, id: CharacterId) {
-        match &mut self.usage {
-            BuildingUsage::Apartments(homes) => {
-                homes.iter_mut().for_each(|home| home.remove_occupant(id))
-            }
-            BuildingUsage::House(home) => home.remove_occupant(id),
-        }
+        self.usage.remove_occupant(id);
+    }
+
+    pub fn update_owner(&mut self, owner: Owner) {
+        let previous_owner = std::mem::replace(&mut self.owner, owner);
+        self.previous_owners.push(previous_owner);
+    }
+
+    pub fn decay(&mut self, value: u32) {
+        self.condition = self.condition.saturating_sub(value);
+    }
+
+    pub fn renovate(&mut self, date: Date) {
+        self.condition = MAX_CONDITION;
+        self.renovation_dates.push(date);
     }
 
-    pub fn update_owner(&mut self, owner: CharacterId) {
-        self.previous_owners.push(self.owner);
-        self.owner = owner;
+    pub fn is_demolished(&self) -> bool {
+        self.demolition_date.is_some()
+    }
+
+    pub fn set_demolition_date(&mut self, date: Date) {
+        if self.is_demolished() {
+            panic!("Building {} is already demolished!", self.id.0);
+        }
+
+        self.demolition_date = Some(date);
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct BuildingMgr {
     buildings: Vec<Building>,
 }
@@ -60,7 +133,7 @@ impl BuildingMgr {
         usage: BuildingUsage,
         construction_date: Date,
         builder: CharacterId,
-        owner: CharacterId,
+        owner: Owner,
     ) -> BuildingId {
         let id = BuildingId::new(self.buildings.len());
         let building = Building::new(id, usage, construction_date, builder, owner, Vec::new());
@@ -94,10 +167,34 @@ mod tests {
             BuildingUsage::house(),
             Date::new(10),
             character_id,
-            character_id,
+            Owner::Character(character_id),
             Vec::new(),
         );
 
         assert_eq!(building.get_age(Date::new(52)), 42);
     }
+
+    #[test]
+    fn decay_and_renovate() {
+        let id = BuildingId::new(0);
+        let character_id = CharacterId::new(0);
+        let mut building = Building::new(
+            id,
+            BuildingUsage::house(),
+            Date::new(10),
+            character_id,
+            Owner::Character(character_id),
+            Vec::new(),
+        );
+
+        building.decay(30);
+        assert_eq!(*building.condition(), 70);
+
+        building.decay(80);
+        assert_eq!(*building.condition(), 0);
+
+        building.renovate(Date::new(20));
+        assert_eq!(*building.condition(), MAX_CONDITION);
+        assert_eq!(building.renovation_dates(), &vec![Date::new(20)]);
+    }
 }
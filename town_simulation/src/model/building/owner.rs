@@ -0,0 +1,191 @@
+use crate::model::character::CharacterId;
+use Owner::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Owner {
+    Character(CharacterId),
+    /// Several characters own shares of the building, which are weighted by the number
+    CoOwners(Vec<(CharacterId, u32)>),
+    Town,
+}
+
+impl Owner {
+    /// Returns the owner for a number of characters with equal shares. Without any characters the town is the owner.
+    pub fn from_characters(characters: Vec<CharacterId>) -> Self {
+        let mut unique = Vec::new();
+
+        for character in characters {
+            if !unique.contains(&character) {
+                unique.push(character);
+            }
+        }
+
+        Self::from_shares(unique.into_iter().map(|id| (id, 1)).collect())
+    }
+
+    /// Merges the shares of the same character & reduces them to the smallest numbers.
+    pub fn from_shares(shares: Vec<(CharacterId, u32)>) -> Self {
+        let mut merged: Vec<(CharacterId, u32)> = Vec::new();
+
+        for (id, share) in shares.into_iter().filter(|(_, share)| *share > 0) {
+            if let Some(entry) = merged.iter_mut().find(|(owner, _)| *owner == id) {
+                entry.1 += share;
+            } else {
+                merged.push((id, share));
+            }
+        }
+
+        let divisor = merged
+            .iter()
+            .fold(0, |divisor, (_, share)| gcd(divisor, *share));
+
+        match merged.len() {
+            0 => Town,
+            1 => Character(merged[0].0),
+            _ => CoOwners(
+                merged
+                    .into_iter()
+                    .map(|(id, share)| (id, share / divisor))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn get_characters(&self) -> Vec<CharacterId> {
+        self.get_shares().into_iter().map(|(id, _)| id).collect()
+    }
+
+    pub fn get_shares(&self) -> Vec<(CharacterId, u32)> {
+        match self {
+            Character(id) => vec![(*id, 1)],
+            CoOwners(shares) => shares.clone(),
+            Town => Vec::new(),
+        }
+    }
+
+    /// Returns the numerator & denominator of the character's share.
+    pub fn get_share(&self, id: CharacterId) -> (u32, u32) {
+        let shares = self.get_shares();
+        let total = shares.iter().map(|(_, share)| share).sum::<u32>().max(1);
+        let share = shares
+            .iter()
+            .find(|(owner, _)| *owner == id)
+            .map(|(_, share)| *share)
+            .unwrap_or_default();
+
+        (share, total)
+    }
+
+    /// Splits the character's share equally among the heirs, while the other owners keep theirs.
+    /// Without heirs the share is divided among the other owners.
+    pub fn pass_share(&self, id: CharacterId, heirs: &[CharacterId]) -> Self {
+        let shares = self.get_shares();
+        let Some((_, share)) = shares.iter().find(|(owner, _)| *owner == id).copied() else {
+            return self.clone();
+        };
+        let factor = heirs.len().max(1) as u32;
+
+        Self::from_shares(
+            shares
+                .into_iter()
+                .filter(|(owner, _)| *owner != id)
+                .map(|(owner, other)| (owner, other * factor))
+                .chain(heirs.iter().map(|heir| (*heir, share)))
+                .collect(),
+        )
+    }
+
+    pub fn is_owned_by(&self, id: CharacterId) -> bool {
+        match self {
+            Character(owner) => *owner == id,
+            CoOwners(owners) => owners.iter().any(|(owner, _)| *owner == id),
+            Town => false,
+        }
+    }
+
+    pub fn is_town(&self) -> bool {
+        matches!(self, Town)
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_characters() {
+        let id0 = CharacterId::new(0);
+        let id1 = CharacterId::new(1);
+
+        assert_eq!(Owner::from_characters(vec![]), Town);
+        assert_eq!(Owner::from_characters(vec![id0]), Character(id0));
+        assert_eq!(Owner::from_characters(vec![id0, id0]), Character(id0));
+        assert_eq!(
+            Owner::from_characters(vec![id0, id1, id0]),
+            CoOwners(vec![(id0, 1), (id1, 1)])
+        );
+    }
+
+    #[test]
+    fn test_from_shares() {
+        let id0 = CharacterId::new(0);
+        let id1 = CharacterId::new(1);
+
+        assert_eq!(Owner::from_shares(vec![(id0, 0)]), Town);
+        assert_eq!(Owner::from_shares(vec![(id0, 2), (id1, 0)]), Character(id0));
+        assert_eq!(
+            Owner::from_shares(vec![(id0, 2), (id1, 4), (id0, 2)]),
+            CoOwners(vec![(id0, 1), (id1, 1)])
+        );
+    }
+
+    #[test]
+    fn test_pass_share() {
+        let id0 = CharacterId::new(0);
+        let id1 = CharacterId::new(1);
+        let id2 = CharacterId::new(2);
+        let id3 = CharacterId::new(3);
+        let owner = Owner::from_characters(vec![id0, id1]);
+
+        assert_eq!(
+            owner.pass_share(id0, &[id2, id3]),
+            CoOwners(vec![(id1, 2), (id2, 1), (id3, 1)])
+        );
+        assert_eq!(owner.pass_share(id0, &[]), Character(id1));
+        assert_eq!(owner.pass_share(id2, &[id3]), owner);
+        assert_eq!(Character(id0).pass_share(id0, &[id1]), Character(id1));
+        assert_eq!(owner.pass_share(id0, &[id1]), Character(id1));
+    }
+
+    #[test]
+    fn test_get_share() {
+        let id0 = CharacterId::new(0);
+        let id1 = CharacterId::new(1);
+        let owner = CoOwners(vec![(id0, 3), (id1, 1)]);
+
+        assert_eq!(owner.get_share(id0), (3, 4));
+        assert_eq!(owner.get_share(CharacterId::new(2)), (0, 4));
+        assert_eq!(Character(id0).get_share(id0), (1, 1));
+    }
+
+    #[test]
+    fn test_is_owned_by() {
+        let id0 = CharacterId::new(0);
+        let id1 = CharacterId::new(1);
+        let id2 = CharacterId::new(2);
+
+        assert!(Character(id0).is_owned_by(id0));
+        assert!(!Character(id0).is_owned_by(id1));
+        assert!(CoOwners(vec![(id0, 1), (id1, 1)]).is_owned_by(id1));
+        assert!(!CoOwners(vec![(id0, 1), (id1, 1)]).is_owned_by(id2));
+        assert!(!Town.is_owned_by(id0));
+    }
+}
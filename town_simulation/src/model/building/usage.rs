@@ -1,8 +1,9 @@
 use crate::model::character::CharacterId;
+use crate::model::town::map::LotSize;
 use derive_getters::Getters;
 use derive_more::Constructor;
 use std::fmt::{Display, Formatter};
-use BuildingUsage::{Apartments, House};
+use BuildingUsage::{Apartments, House, MarketSquare, Temple};
 
 #[derive(Constructor, Default, Getters, Clone, Debug, PartialEq)]
 pub struct Home {
@@ -27,6 +28,8 @@ impl Home {
 pub enum BuildingUsage {
     Apartments(Vec<Home>),
     House(Home),
+    MarketSquare,
+    Temple,
 }
 
 impl BuildingUsage {
@@ -34,6 +37,10 @@ impl BuildingUsage {
         House(Home::default())
     }
 
+    pub fn apartments(homes: usize) -> Self {
+        Apartments(vec![Home::default(); homes])
+    }
+
     pub fn is_apartments(&self) -> bool {
         matches!(self, Apartments(..))
     }
@@ -41,6 +48,65 @@ impl BuildingUsage {
     pub fn is_house(&self) -> bool {
         matches!(self, House(..))
     }
+
+    /// Public buildings are maintained by the town & never sold.
+    pub fn is_public(&self) -> bool {
+        matches!(self, MarketSquare | Temple)
+    }
+
+    pub fn get_homes(&self) -> Vec<&Home> {
+        match self {
+            Apartments(homes) => homes.iter().collect(),
+            House(home) => vec![home],
+            MarketSquare | Temple => Vec::new(),
+        }
+    }
+
+    pub fn has_empty_home(&self) -> bool {
+        self.get_homes().iter().any(|home| home.is_empty())
+    }
+
+    pub fn get_empty_home_mut(&mut self) -> Option<&mut Home> {
+        match self {
+            Apartments(homes) => homes.iter_mut().find(|home| home.is_empty()),
+            House(home) => Some(home).filter(|home| home.is_empty()),
+            MarketSquare | Temple => None,
+        }
+    }
+
+    pub fn get_home_of_mut(&mut self, id: CharacterId) -> Option<&mut Home> {
+        match self {
+            Apartments(homes) => homes.iter_mut().find(|home| home.occupants().contains(&id)),
+            House(home) => Some(home).filter(|home| home.occupants().contains(&id)),
+            MarketSquare | Temple => None,
+        }
+    }
+
+    pub fn remove_occupant(&mut self, id: CharacterId) {
+        match self {
+            Apartments(homes) => homes.iter_mut().for_each(|home| home.remove_occupant(id)),
+            House(home) => home.remove_occupant(id),
+            MarketSquare | Temple => {}
+        }
+    }
+
+    pub fn get_lot_size(&self) -> LotSize {
+        match self {
+            Apartments(_) => LotSize::Double,
+            House(_) => LotSize::Single,
+            MarketSquare | Temple => LotSize::Block,
+        }
+    }
+
+    /// Returns the number of years needed to construct the building.
+    pub fn get_construction_time(&self) -> u32 {
+        match self {
+            Apartments(_) => 4,
+            House(_) => 2,
+            MarketSquare => 1,
+            Temple => 10,
+        }
+    }
 }
 
 impl Display for BuildingUsage {
@@ -48,6 +114,8 @@ impl Display for BuildingUsage {
         match self {
             Apartments(_) => write!(f, "Apartment Building"),
             House(_) => write!(f, "House"),
+            MarketSquare => write!(f, "Market Square"),
+            Temple => write!(f, "Temple"),
         }
     }
 }
@@ -1,7 +1,8 @@
 use crate::model::character::gender::Gender::{Female, Male};
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum Gender {
+    #[default]
     Male,
     Female,
 }
@@ -19,12 +20,6 @@ impl Gender {
     }
 }
 
-impl Default for Gender {
-    fn default() -> Self {
-        Gender::Male
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
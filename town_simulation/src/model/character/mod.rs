@@ -5,6 +5,7 @@ use crate::model::character::relation::building::BuildingRelation;
 use crate::model::character::relation::building::BuildingRelationType::{ExOwner, Occupant, Owner};
 use crate::model::character::relation::character::CharacterRelation;
 use crate::model::time::Date;
+use crate::model::town::TownId;
 use derive_getters::Getters;
 use derive_more::Constructor;
 
@@ -28,6 +29,10 @@ pub struct Character {
     gender: Gender,
     birth_date: Date,
     death_date: Option<Date>,
+    emigration_date: Option<Date>,
+    /// The town the character lives in. Its relations to buildings refer to buildings of this town.
+    town: TownId,
+    testament: Option<CharacterId>,
     pub character_relations: Vec<CharacterRelation>,
     building_relations: Vec<BuildingRelation>,
 }
@@ -40,6 +45,9 @@ impl Character {
             gender: Gender::default(),
             birth_date: Date::default(),
             death_date: None,
+            emigration_date: None,
+            town: TownId::default(),
+            testament: None,
             character_relations: Vec::new(),
             building_relations: Vec::new(),
         }
@@ -82,6 +90,43 @@ impl Character {
         self.death_date = Some(death_date);
     }
 
+    pub fn has_emigrated(&self) -> bool {
+        self.emigration_date.is_some()
+    }
+
+    /// Returns true, if the character is alive & still lives in the town.
+    pub fn is_resident(&self) -> bool {
+        self.is_alive() && !self.has_emigrated()
+    }
+
+    /// Returns true, if the character is a resident of the specific town.
+    pub fn lives_in(&self, town: TownId) -> bool {
+        self.is_resident() && self.town == town
+    }
+
+    pub fn set_town(&mut self, town: TownId) {
+        self.town = town;
+    }
+
+    /// The character moves to another town, but forgets all relations to buildings of the old town.
+    pub fn immigrate(&mut self, town: TownId) {
+        self.town = town;
+        self.emigration_date = None;
+        self.building_relations.clear();
+    }
+
+    pub fn set_emigration_date(&mut self, emigration_date: Date) {
+        if self.has_emigrated() {
+            panic!("Character has already emigrated!");
+        }
+
+        self.emigration_date = Some(emigration_date);
+    }
+
+    pub fn set_testament(&mut self, heir: Option<CharacterId>) {
+        self.testament = heir;
+    }
+
     pub fn get_building_relations_mut(&mut self) -> &mut Vec<BuildingRelation> {
         &mut self.building_relations
     }
@@ -106,7 +151,7 @@ impl Character {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct CharacterMgr {
     characters: Vec<Character>,
 }
@@ -35,6 +35,14 @@ impl CharacterName {
         }
     }
 
+    pub fn get_first(&self) -> &str {
+        match self {
+            Simple(name) => name,
+            Standard { first, .. } => first,
+            Married { first, .. } => first,
+        }
+    }
+
     pub fn get_last(&self) -> Option<&str> {
         match self {
             Simple(..) => None,
@@ -43,6 +51,15 @@ impl CharacterName {
         }
     }
 
+    /// Returns the last name before the marriage.
+    pub fn get_birth_last(&self) -> Option<&str> {
+        match self {
+            Simple(..) => None,
+            Standard { last, .. } => Some(last),
+            Married { birth, .. } => Some(birth),
+        }
+    }
+
     pub fn marry<S: Into<String>>(&self, new_last: S) -> Self {
         match self {
             Simple(..) => self.clone(),
@@ -53,6 +70,16 @@ impl CharacterName {
         }
     }
 
+    pub fn adopt<S: Into<String>>(&self, new_last: S) -> Self {
+        match self {
+            Simple(..) => self.clone(),
+            Standard { first, .. } => Self::standard(first.to_string(), new_last.into()),
+            Married { first, last, .. } => {
+                Self::married(first.to_string(), last.to_string(), new_last.into())
+            }
+        }
+    }
+
     fn check_came_last_name(&self, first: &str, last: String, birth: &str) -> Self {
         if last.eq(birth) {
             Self::standard(first.to_string(), last)
@@ -110,6 +137,17 @@ mod tests {
         assert_eq!(married.sorted(), "Ccc nee Bbb, Aaa");
     }
 
+    #[test]
+    fn test_get_first() {
+        let simple = CharacterName::simple("Test");
+        let standard = CharacterName::standard("Aaa", "Bbb");
+        let married = CharacterName::married("Aaa", "Ccc", "Bbb");
+
+        assert_eq!(simple.get_first(), "Test");
+        assert_eq!(standard.get_first(), "Aaa");
+        assert_eq!(married.get_first(), "Aaa");
+    }
+
     #[test]
     fn test_get_last() {
         let simple = CharacterName::simple("Test");
@@ -121,6 +159,17 @@ mod tests {
         assert_eq!(married.get_last(), Some("Ccc"));
     }
 
+    #[test]
+    fn test_get_birth_last() {
+        let simple = CharacterName::simple("Test");
+        let standard = CharacterName::standard("Aaa", "Bbb");
+        let married = CharacterName::married("Aaa", "Ccc", "Bbb");
+
+        assert_eq!(simple.get_birth_last(), None);
+        assert_eq!(standard.get_birth_last(), Some("Bbb"));
+        assert_eq!(married.get_birth_last(), Some("Bbb"));
+    }
+
     #[test]
     fn test_marry() {
         let simple = CharacterName::simple("Test");
@@ -133,6 +182,20 @@ mod tests {
         assert_eq!(married.marry("Ddd"), married2);
     }
 
+    #[test]
+    fn test_adopt() {
+        let simple = CharacterName::simple("Test");
+        let standard = CharacterName::standard("Aaa", "Bbb");
+        let married = CharacterName::married("Aaa", "Ccc", "Bbb");
+
+        assert_eq!(simple.adopt("Ddd"), simple);
+        assert_eq!(standard.adopt("Ddd"), CharacterName::standard("Aaa", "Ddd"));
+        assert_eq!(
+            married.adopt("Ddd"),
+            CharacterName::married("Aaa", "Ccc", "Ddd")
+        );
+    }
+
     #[test]
     fn test_marry_with_same_last_name() {
         let character = CharacterName::standard("Aaa", "Bbb");
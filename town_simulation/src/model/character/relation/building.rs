@@ -19,7 +19,7 @@ pub struct BuildingRelation {
 
 impl PartialOrd<Self> for BuildingRelation {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.relation_type.partial_cmp(&other.relation_type)
+        Some(self.cmp(other))
     }
 }
 
@@ -10,11 +10,19 @@ pub enum RelativeType {
     /// Uncle or Aunt
     Pibling,
     Parent,
+    StepParent,
+    AdoptiveParent,
     Cousin,
     Sibling,
+    /// Shares only one parent
+    HalfSibling,
+    /// Shares no parent, but one parent is married to a parent of the other
+    StepSibling,
     /// Nephew or Niece
     Nibling,
     Child,
+    StepChild,
+    AdoptedChild,
     GrandChild,
 }
 
@@ -25,9 +33,15 @@ impl RelativeType {
             Pibling => Nibling,
             Nibling => Pibling,
             Parent => Child,
+            StepParent => StepChild,
+            AdoptiveParent => AdoptedChild,
             Cousin => Cousin,
             Sibling => Sibling,
+            HalfSibling => HalfSibling,
+            StepSibling => StepSibling,
             Child => Parent,
+            StepChild => StepParent,
+            AdoptedChild => AdoptiveParent,
             GrandChild => GrandParent,
         }
     }
@@ -39,9 +53,15 @@ impl RelativeType {
                 Pibling => "uncle",
                 Nibling => "nephew",
                 Parent => "father",
+                StepParent => "stepfather",
+                AdoptiveParent => "adoptive father",
                 Cousin => "cousin",
                 Sibling => "brother",
+                HalfSibling => "half-brother",
+                StepSibling => "stepbrother",
                 Child => "son",
+                StepChild => "stepson",
+                AdoptedChild => "adopted son",
                 GrandChild => "grandson",
             }
         } else {
@@ -50,9 +70,15 @@ impl RelativeType {
                 Pibling => "aunt",
                 Nibling => "niece",
                 Parent => "mother",
+                StepParent => "stepmother",
+                AdoptiveParent => "adoptive mother",
                 Cousin => "cousin",
                 Sibling => "sister",
+                HalfSibling => "half-sister",
+                StepSibling => "stepsister",
                 Child => "daughter",
+                StepChild => "stepdaughter",
+                AdoptedChild => "adopted daughter",
                 GrandChild => "granddaughter",
             }
         }
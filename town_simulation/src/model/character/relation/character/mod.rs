@@ -14,6 +14,9 @@ pub enum CharacterRelationType {
     InLaw(RelativeType),
     Relative(RelativeType),
     Spouse,
+    /// Takes care of an orphan
+    Guardian,
+    Ward,
 }
 
 impl CharacterRelationType {
@@ -22,6 +25,8 @@ impl CharacterRelationType {
             InLaw(relative_type) => InLaw(relative_type.reverse()),
             Relative(relative_type) => Relative(relative_type.reverse()),
             Spouse => Spouse,
+            Guardian => Ward,
+            Ward => Guardian,
         }
     }
 
@@ -33,6 +38,10 @@ impl CharacterRelationType {
         matches!(self, Relative(..))
     }
 
+    pub fn is_guardianship(&self) -> bool {
+        matches!(self, Guardian | Ward)
+    }
+
     pub fn get_gender_specific_string(&self, gender: Gender) -> String {
         match self {
             InLaw(relative_type) => format!(
@@ -41,6 +50,8 @@ impl CharacterRelationType {
             ),
             Relative(relative_type) => relative_type.get_gender_specific_string(gender).to_string(),
             Spouse => if gender == Male { "husband" } else { "wife" }.to_string(),
+            Guardian => "guardian".to_string(),
+            Ward => "ward".to_string(),
         }
     }
 }
@@ -62,7 +73,7 @@ impl CharacterRelation {
 
 impl PartialOrd<Self> for CharacterRelation {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.relation_type.partial_cmp(&other.relation_type)
+        Some(self.cmp(other))
     }
 }
 
@@ -0,0 +1,41 @@
+use crate::model::building::owner::Owner;
+use crate::model::building::BuildingId;
+use crate::model::character::CharacterId;
+use crate::model::time::Date;
+use crate::model::town::TownId;
+use derive_getters::Getters;
+use derive_more::Constructor;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SimulationEvent {
+    Birth {
+        child: CharacterId,
+        parents: (CharacterId, CharacterId),
+    },
+    Death(CharacterId),
+    Marriage {
+        husband: CharacterId,
+        wife: CharacterId,
+    },
+    /// A building was built or its construction started.
+    Build {
+        building: BuildingId,
+        builder: CharacterId,
+    },
+    OwnershipChange {
+        building: BuildingId,
+        previous: Owner,
+        new: Owner,
+    },
+    Relocation {
+        characters: Vec<CharacterId>,
+        building: BuildingId,
+    },
+}
+
+#[derive(Constructor, Getters, Clone, Debug, Eq, PartialEq)]
+pub struct EventRecord {
+    date: Date,
+    town: TownId,
+    event: SimulationEvent,
+}
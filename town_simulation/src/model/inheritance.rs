@@ -0,0 +1,40 @@
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum InheritanceLaw {
+    /// The eldest descendant inherits everything. The spouse only inherits without descendants.
+    Primogeniture,
+    /// The youngest descendant inherits everything. The spouse only inherits without descendants.
+    Ultimogeniture,
+    /// All children (or grandchildren) inherit equal shares & become co-owners.
+    EqualPartition,
+    /// The spouse inherits everything, otherwise the eldest descendant.
+    #[default]
+    SpouseThenChildren,
+    /// The heir named in the testament inherits, otherwise [`InheritanceLaw::SpouseThenChildren`] applies.
+    Testament,
+}
+
+impl InheritanceLaw {
+    pub const ALL: [InheritanceLaw; 5] = [
+        InheritanceLaw::Primogeniture,
+        InheritanceLaw::Ultimogeniture,
+        InheritanceLaw::EqualPartition,
+        InheritanceLaw::SpouseThenChildren,
+        InheritanceLaw::Testament,
+    ];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        InheritanceLaw::ALL
+            .into_iter()
+            .find(|law| law.get_name() == name)
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            InheritanceLaw::Primogeniture => "primogeniture",
+            InheritanceLaw::Ultimogeniture => "ultimogeniture",
+            InheritanceLaw::EqualPartition => "equal_partition",
+            InheritanceLaw::SpouseThenChildren => "spouse_then_children",
+            InheritanceLaw::Testament => "testament",
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod building;
 pub mod character;
+pub mod event;
+pub mod inheritance;
 pub mod time;
 pub mod town;
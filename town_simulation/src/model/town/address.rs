@@ -0,0 +1,28 @@
+use derive_getters::Getters;
+use derive_more::Constructor;
+use std::fmt::{Display, Formatter};
+
+/// Houses on the northern side of a street have odd numbers & the others even numbers.
+#[derive(Constructor, Getters, Clone, Debug, Eq, PartialEq)]
+pub struct Address {
+    number: usize,
+    street: String,
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.number, self.street)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let address = Address::new(12, "Mill Street".to_string());
+
+        assert_eq!(address.to_string(), "12 Mill Street");
+    }
+}
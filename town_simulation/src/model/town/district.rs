@@ -0,0 +1,72 @@
+use crate::model::building::usage::BuildingUsage;
+use District::*;
+
+/// The land use of a block, which depends on its distance to the centre of the town.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum District {
+    Centre,
+    Market,
+    Residential,
+    Outskirts,
+    Farmland,
+}
+
+impl District {
+    pub fn from_distance(distance: usize) -> Self {
+        match distance {
+            0 => Centre,
+            1 => Market,
+            2..=3 => Residential,
+            4..=5 => Outskirts,
+            _ => Farmland,
+        }
+    }
+
+    pub fn is_allowed(&self, usage: &BuildingUsage) -> bool {
+        match self {
+            Centre | Outskirts => true,
+            Market => !usage.is_house(),
+            Residential | Farmland => usage.is_house(),
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Centre => "centre",
+            Market => "market",
+            Residential => "residential",
+            Outskirts => "outskirts",
+            Farmland => "farmland",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_distance() {
+        assert_eq!(District::from_distance(0), Centre);
+        assert_eq!(District::from_distance(1), Market);
+        assert_eq!(District::from_distance(3), Residential);
+        assert_eq!(District::from_distance(4), Outskirts);
+        assert_eq!(District::from_distance(9), Farmland);
+    }
+
+    #[test]
+    fn test_is_allowed() {
+        let house = BuildingUsage::house();
+        let apartments = BuildingUsage::apartments(4);
+
+        assert!(Centre.is_allowed(&house));
+        assert!(Centre.is_allowed(&apartments));
+        assert!(!Market.is_allowed(&house));
+        assert!(Market.is_allowed(&apartments));
+        assert!(Market.is_allowed(&BuildingUsage::MarketSquare));
+        assert!(Residential.is_allowed(&house));
+        assert!(!Residential.is_allowed(&apartments));
+        assert!(Farmland.is_allowed(&house));
+        assert!(!Farmland.is_allowed(&apartments));
+    }
+}
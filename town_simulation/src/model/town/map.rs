@@ -1,4 +1,6 @@
 use crate::model::building::BuildingId;
+use crate::model::town::address::Address;
+use crate::model::town::district::District;
 use crate::model::town::map::TownBlock::*;
 use crate::model::town::map::TownLot::*;
 use derive_getters::Getters;
@@ -6,26 +8,95 @@ use derive_getters::Getters;
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TownLot {
     EmptyLot,
+    ConstructionSite(BuildingId),
     BuildingLot(BuildingId),
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TownBlock {
     EmptyBlock,
+    /// The 4 lots of the block. A building may occupy several lots.
     SmallBuildings([TownLot; 4]),
+    /// A single building like a temple or the market square fills the whole block.
+    LargeBuilding(TownLot),
+    Park,
+    Farmland,
+    Forest,
+    Hills,
+    Mill,
+    /// A river or the sea
+    Water,
 }
 
 impl TownBlock {
     pub fn empty() -> Self {
         SmallBuildings([EmptyLot; 4])
     }
+
+    /// Returns the lot, if buildings can be placed on this block.
+    pub fn get_lot(&self, lot: usize) -> Option<TownLot> {
+        match self {
+            SmallBuildings(lots) => Some(lots[lot]),
+            LargeBuilding(town_lot) => Some(*town_lot),
+            EmptyBlock => Some(EmptyLot),
+            Park | Farmland | Forest | Hills | Mill | Water => None,
+        }
+    }
+
+    fn get_lots_mut(&mut self) -> Vec<&mut TownLot> {
+        match self {
+            SmallBuildings(lots) => lots.iter_mut().collect(),
+            LargeBuilding(town_lot) => vec![town_lot],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LotSize {
+    Single,
+    /// 2 neighbouring lots facing the same street
+    Double,
+    /// All 4 lots of a block
+    Block,
 }
 
+impl LotSize {
+    /// Returns the lots occupied by a building starting at the lot or none, if it doesn't fit.
+    pub fn get_lots(&self, lot: usize) -> Option<Vec<usize>> {
+        match self {
+            LotSize::Single => Some(vec![lot]),
+            LotSize::Double if lot.is_multiple_of(2) => Some(vec![lot, lot + 1]),
+            LotSize::Block if lot == 0 => Some(vec![0, 1, 2, 3]),
+            _ => None,
+        }
+    }
+}
+
+pub const CENTRE_LAND_VALUE: u32 = 100;
+
+/// The blocks are arranged in rows & separated by streets.
+/// Each block contains 2x2 lots & the lots 0 & 1 face the street north of the block,
+/// while the lots 2 & 3 face the street south of it.
 #[derive(Getters, Clone, Debug, PartialEq)]
 pub struct TownMap {
     width: usize,
     height: usize,
     blocks: Vec<TownBlock>,
+    districts: Vec<District>,
+    /// The column & row of each block. The blocks of new columns are appended,
+    /// so the indices of existing blocks never change.
+    positions: Vec<(usize, usize)>,
+    /// The index of the block at each column & row from west to east & north to south.
+    grid: Vec<usize>,
+    /// The column & row of the centre block, which doesn't move when the map grows.
+    centre: (usize, usize),
+    /// The streets from north to south. There is one more street than rows of blocks.
+    horizontal_streets: Vec<String>,
+    /// The streets from west to east. There is one more street than columns of blocks.
+    vertical_streets: Vec<String>,
+    max_width: usize,
+    max_height: usize,
 }
 
 impl TownMap {
@@ -39,63 +110,312 @@ impl TownMap {
             );
         }
 
+        let centre = (width / 2, height / 2);
+        let positions: Vec<(usize, usize)> = (0..blocks.len())
+            .map(|index| (index % width, index / width))
+            .collect();
+        let districts = positions
+            .iter()
+            .map(|position| District::from_distance(get_distance(centre, *position)))
+            .collect();
+
         Self {
             width,
             height,
+            grid: (0..blocks.len()).collect(),
             blocks,
+            districts,
+            positions,
+            centre,
+            horizontal_streets: default_street_names(height + 1, "Street"),
+            vertical_streets: default_street_names(width + 1, "Road"),
+            max_width: usize::MAX,
+            max_height: usize::MAX,
         }
     }
 
     pub fn empty(width: usize, height: usize) -> Self {
-        Self {
-            width,
-            height,
-            blocks: vec![EmptyBlock; width * height],
+        Self::new(width, height, vec![EmptyBlock; width * height])
+    }
+
+    pub fn set_street_names(&mut self, horizontal: Vec<String>, vertical: Vec<String>) {
+        if horizontal.len() != self.height + 1 || vertical.len() != self.width + 1 {
+            panic!(
+                "{} horizontal & {} vertical streets don't match the map!",
+                horizontal.len(),
+                vertical.len()
+            );
         }
+
+        self.horizontal_streets = horizontal;
+        self.vertical_streets = vertical;
     }
 
-    pub fn is_lot_free(&self, block: usize, lot: usize) -> bool {
-        if let Some(SmallBuildings(buildings)) = self.blocks.get(block) {
-            return buildings[lot] == EmptyLot;
+    pub fn set_max_size(&mut self, width: usize, height: usize) {
+        self.max_width = width;
+        self.max_height = height;
+    }
+
+    pub fn can_add_row(&self) -> bool {
+        self.height < self.max_height
+    }
+
+    pub fn can_add_column(&self) -> bool {
+        self.width < self.max_width
+    }
+
+    pub fn can_grow(&self) -> bool {
+        self.can_add_row() || self.can_add_column()
+    }
+
+    /// Adds a row of empty blocks & a street to the south of the map.
+    pub fn add_row(&mut self, street: String) {
+        if !self.can_add_row() {
+            panic!("Failed to add a row, because of the maximum height!");
+        }
+
+        for column in 0..self.width {
+            self.grid.push(self.blocks.len());
+            self.push_block((column, self.height));
+        }
+
+        self.height += 1;
+        self.horizontal_streets.push(street);
+    }
+
+    /// Adds a column of empty blocks & a street to the east of the map.
+    pub fn add_column(&mut self, street: String) {
+        if !self.can_add_column() {
+            panic!("Failed to add a column, because of the maximum width!");
         }
 
-        true
+        let mut grid = Vec::with_capacity((self.width + 1) * self.height);
+
+        for row in 0..self.height {
+            grid.extend_from_slice(&self.grid[row * self.width..(row + 1) * self.width]);
+            grid.push(self.blocks.len());
+            self.push_block((self.width, row));
+        }
+
+        self.grid = grid;
+        self.width += 1;
+        self.vertical_streets.push(street);
+    }
+
+    fn push_block(&mut self, position: (usize, usize)) {
+        let distance = get_distance(self.centre, position);
+        self.blocks.push(EmptyBlock);
+        self.districts.push(District::from_distance(distance));
+        self.positions.push(position);
+    }
+
+    pub fn get_position(&self, block: usize) -> (usize, usize) {
+        self.positions[block]
+    }
+
+    pub fn get_index(&self, column: usize, row: usize) -> usize {
+        self.grid[row * self.width + column]
+    }
+
+    pub fn get_district(&self, block: usize) -> District {
+        self.districts[block]
+    }
+
+    pub fn set_district(&mut self, block: usize, district: District) {
+        self.districts[block] = district;
+    }
+
+    pub fn get_neighbours(&self, block: usize) -> Vec<usize> {
+        let (column, row) = self.get_position(block);
+        let mut neighbours = Vec::new();
+
+        if row > 0 {
+            neighbours.push(self.get_index(column, row - 1));
+        }
+        if column > 0 {
+            neighbours.push(self.get_index(column - 1, row));
+        }
+        if column + 1 < self.width {
+            neighbours.push(self.get_index(column + 1, row));
+        }
+        if row + 1 < self.height {
+            neighbours.push(self.get_index(column, row + 1));
+        }
+
+        neighbours
+    }
+
+    pub fn get_centre_block(&self) -> usize {
+        self.get_index(self.centre.0, self.centre.1)
+    }
+
+    /// Returns the number of blocks to walk to the centre block.
+    pub fn get_distance_to_centre(&self, block: usize) -> usize {
+        get_distance(self.centre, self.get_position(block))
+    }
+
+    /// The land value falls with the distance to the centre.
+    pub fn get_land_value(&self, block: usize) -> u32 {
+        let distance = self.get_distance_to_centre(block) as u32;
+
+        CENTRE_LAND_VALUE * 2 / (2 + distance)
+    }
+
+    pub fn get_free_lots(&self, size: LotSize) -> Vec<(usize, usize)> {
+        (0..self.blocks.len())
+            .flat_map(|block| (0..4).map(move |lot| (block, lot)))
+            .filter(|(block, lot)| self.is_space_free(*block, *lot, size))
+            .collect()
+    }
+
+    /// Returns the x & y coordinates of a lot, where each block is 2 lots wide & high.
+    pub fn get_lot_position(&self, block: usize, lot: usize) -> (usize, usize) {
+        let (column, row) = self.get_position(block);
+
+        (column * 2 + lot % 2, row * 2 + lot / 2)
+    }
+
+    pub fn get_address(&self, block: usize, lot: usize) -> Address {
+        let (_, row) = self.get_position(block);
+        let (x, _) = self.get_lot_position(block, lot);
+        let is_north_side = lot / 2 == 1;
+        let number = if is_north_side { x * 2 + 1 } else { x * 2 + 2 };
+
+        Address::new(number, self.horizontal_streets[row + lot / 2].clone())
+    }
+
+    /// Returns the block & first lot of a building or construction site.
+    pub fn find_building(&self, id: BuildingId) -> Option<(usize, usize)> {
+        (0..self.blocks.len())
+            .flat_map(|block| (0..4).map(move |lot| (block, lot)))
+            .find(|(block, lot)| self.get_building(*block, *lot) == Some(id))
+    }
+
+    pub fn is_lot_free(&self, block: usize, lot: usize) -> bool {
+        self.blocks
+            .get(block)
+            .map(|town_block| town_block.get_lot(lot) == Some(EmptyLot))
+            .unwrap_or(true)
+    }
+
+    pub fn is_space_free(&self, block: usize, lot: usize, size: LotSize) -> bool {
+        size.get_lots(lot)
+            .map(|lots| lots.iter().all(|lot| self.is_lot_free(block, *lot)))
+            .unwrap_or(false)
     }
 
     pub fn get_block(&self, row: usize, column: usize) -> &TownBlock {
-        let index = row * self.width + column;
-        &self.blocks[index]
+        &self.blocks[self.get_index(column, row)]
+    }
+
+    /// Replaces a block without buildings, e.g. with a park.
+    pub fn set_block(&mut self, index: usize, block: TownBlock) {
+        if self.blocks[index]
+            .get_lots_mut()
+            .iter()
+            .any(|lot| **lot != EmptyLot)
+        {
+            panic!("Failed to replace block {} with buildings!", index);
+        }
+
+        self.blocks[index] = block;
     }
 
+    /// Returns the building or construction site of a lot.
     pub fn get_building(&self, block: usize, lot: usize) -> Option<BuildingId> {
-        if let Some(SmallBuildings(buildings)) = self.blocks.get(block) {
-            if let BuildingLot(building) = buildings[lot] {
-                return Some(building);
-            }
+        match self
+            .blocks
+            .get(block)
+            .and_then(|town_block| town_block.get_lot(lot))
+        {
+            Some(ConstructionSite(building) | BuildingLot(building)) => Some(building),
+            _ => None,
         }
+    }
+
+    pub fn add_building(&mut self, id: BuildingId, block: usize, lot: usize, size: LotSize) {
+        self.set_lots(block, lot, size, BuildingLot(id));
+    }
 
-        None
+    pub fn add_construction_site(
+        &mut self,
+        id: BuildingId,
+        block: usize,
+        lot: usize,
+        size: LotSize,
+    ) {
+        self.set_lots(block, lot, size, ConstructionSite(id));
     }
 
-    pub fn add_building(&mut self, id: BuildingId, block: usize, lot: usize) {
+    fn set_lots(&mut self, block: usize, lot: usize, size: LotSize, town_lot: TownLot) {
+        if !self.is_space_free(block, lot, size) {
+            panic!(
+                "Failed to add {:?} with size {:?} to lot {} of block {}!",
+                town_lot, size, lot, block
+            );
+        }
+
+        if size == LotSize::Block {
+            self.blocks[block] = LargeBuilding(town_lot);
+            return;
+        }
+
         if self.blocks[block] == EmptyBlock {
             self.blocks[block] = TownBlock::empty();
         }
 
-        if self.is_lot_free(block, lot) {
-            if let Some(SmallBuildings(buildings)) = self.blocks.get_mut(block) {
-                buildings[lot] = BuildingLot(id);
-                return;
+        if let SmallBuildings(buildings) = &mut self.blocks[block] {
+            for lot in size.get_lots(lot).unwrap() {
+                buildings[lot] = town_lot;
             }
         }
+    }
 
-        panic!(
-            "Failed to add building {} to lot {} of block {}!",
-            id.id(),
-            lot,
-            block
-        );
+    pub fn complete_building(&mut self, id: BuildingId) {
+        let mut is_found = false;
+
+        for lot in self.get_lots_of_mut(id) {
+            *lot = BuildingLot(id);
+            is_found = true;
+        }
+
+        if !is_found {
+            panic!("Failed to complete unknown construction site {}!", id.id());
+        }
     }
+
+    pub fn remove_building(&mut self, id: BuildingId) {
+        let (block, _) = self
+            .find_building(id)
+            .unwrap_or_else(|| panic!("Failed to remove unknown building {}!", id.id()));
+
+        if let LargeBuilding(_) = self.blocks[block] {
+            self.blocks[block] = EmptyBlock;
+            return;
+        }
+
+        for lot in self.get_lots_of_mut(id) {
+            *lot = EmptyLot;
+        }
+    }
+
+    fn get_lots_of_mut(&mut self, id: BuildingId) -> Vec<&mut TownLot> {
+        self.blocks
+            .iter_mut()
+            .flat_map(|block| block.get_lots_mut())
+            .filter(|lot| **lot == BuildingLot(id) || **lot == ConstructionSite(id))
+            .collect()
+    }
+}
+
+fn get_distance(centre: (usize, usize), (column, row): (usize, usize)) -> usize {
+    column.abs_diff(centre.0) + row.abs_diff(centre.1)
+}
+
+fn default_street_names(count: usize, suffix: &str) -> Vec<String> {
+    (1..=count)
+        .map(|index| format!("{} {}", suffix, index))
+        .collect()
 }
 
 impl Default for TownMap {
@@ -120,7 +440,7 @@ mod tests {
         assert!(map.is_lot_free(0, 3));
 
         assert!(map.is_lot_free(1, 0));
-        assert_eq!(map.is_lot_free(1, 1), false);
+        assert!(!map.is_lot_free(1, 1));
         assert!(map.is_lot_free(1, 2));
         assert!(map.is_lot_free(1, 3));
     }
@@ -147,7 +467,7 @@ mod tests {
         let id = BuildingId::new(42);
         let mut map = TownMap::new(2, 1, vec![EmptyBlock, EmptyBlock]);
 
-        map.add_building(id, 0, 3);
+        map.add_building(id, 0, 3, LotSize::Single);
 
         assert_eq!(map.get_building(0, 0), None);
         assert_eq!(map.get_building(0, 1), None);
@@ -167,7 +487,7 @@ mod tests {
         let block = SmallBuildings([EmptyLot, EmptyLot, BuildingLot(id0), EmptyLot]);
         let mut map = TownMap::new(2, 1, vec![EmptyBlock, block]);
 
-        map.add_building(id1, 1, 0);
+        map.add_building(id1, 1, 0, LotSize::Single);
 
         assert_eq!(map.get_building(0, 0), None);
         assert_eq!(map.get_building(0, 1), None);
@@ -180,6 +500,270 @@ mod tests {
         assert_eq!(map.get_building(1, 3), None);
     }
 
+    #[test]
+    fn add_row() {
+        let id = BuildingId::new(42);
+        let mut map = TownMap::empty(2, 1);
+        map.add_building(id, 1, 2, LotSize::Single);
+
+        map.add_row("Street 3".to_string());
+
+        assert_eq!(map.height(), &2);
+        assert_eq!(map.blocks().len(), 4);
+        assert_eq!(map.get_building(1, 2), Some(id));
+        assert_eq!(map.horizontal_streets()[2], "Street 3");
+    }
+
+    #[test]
+    fn add_column() {
+        let id0 = BuildingId::new(42);
+        let id1 = BuildingId::new(43);
+        let mut map = TownMap::empty(2, 2);
+        map.add_building(id0, 1, 2, LotSize::Single);
+        map.add_building(id1, 3, 0, LotSize::Single);
+
+        map.add_column("Road 4".to_string());
+
+        assert_eq!(map.width(), &3);
+        assert_eq!(map.blocks().len(), 6);
+        assert_eq!(map.find_building(id0), Some((1, 2)));
+        assert_eq!(map.find_building(id1), Some((3, 0)));
+        assert_eq!(map.get_position(3), (1, 1));
+        assert_eq!(map.get_position(4), (2, 0));
+        assert_eq!(map.get_index(2, 1), 5);
+        assert_eq!(map.get_neighbours(3), vec![1, 2, 5]);
+        assert_eq!(map.get_block(1, 2), &EmptyBlock);
+        assert_eq!(map.vertical_streets()[3], "Road 4");
+    }
+
+    #[test]
+    fn districts_depend_on_distance_to_centre() {
+        let map = TownMap::empty(3, 3);
+
+        assert_eq!(map.centre(), &(1, 1));
+        assert_eq!(map.get_centre_block(), 4);
+        assert_eq!(map.get_district(4), District::Centre);
+        assert_eq!(map.get_district(1), District::Market);
+        assert_eq!(map.get_district(8), District::Residential);
+        assert_eq!(map.get_distance_to_centre(8), 2);
+        assert_eq!(map.get_land_value(4), 100);
+        assert_eq!(map.get_land_value(1), 66);
+        assert_eq!(map.get_land_value(8), 50);
+    }
+
+    #[test]
+    fn get_neighbours() {
+        let map = TownMap::empty(3, 2);
+
+        assert_eq!(map.get_neighbours(0), vec![1, 3]);
+        assert_eq!(map.get_neighbours(4), vec![1, 3, 5]);
+        assert_eq!(map.get_neighbours(5), vec![2, 4]);
+    }
+
+    #[test]
+    fn growing_keeps_the_districts() {
+        let mut map = TownMap::empty(3, 3);
+        map.set_district(0, District::Farmland);
+
+        map.add_column("Road 5".to_string());
+        map.add_row("Street 5".to_string());
+
+        assert_eq!(map.districts().len(), 16);
+        assert_eq!(map.get_centre_block(), 4);
+        assert_eq!(map.get_district(0), District::Farmland);
+        assert_eq!(map.get_district(4), District::Centre);
+        assert_eq!(map.get_district(10), District::Residential);
+        assert_eq!(map.get_position(15), (3, 3));
+        assert_eq!(map.get_district(15), District::Outskirts);
+        assert_eq!(map.get_distance_to_centre(15), 4);
+    }
+
+    #[test]
+    fn get_free_lots() {
+        let id = BuildingId::new(42);
+        let block = SmallBuildings([BuildingLot(id), EmptyLot, BuildingLot(id), EmptyLot]);
+        let map = TownMap::new(1, 1, vec![block]);
+
+        assert_eq!(map.get_free_lots(LotSize::Single), vec![(0, 1), (0, 3)]);
+        assert_eq!(map.get_free_lots(LotSize::Double), Vec::new());
+    }
+
+    #[test]
+    fn can_grow_until_max_size() {
+        let mut map = TownMap::empty(2, 1);
+        map.set_max_size(2, 2);
+
+        assert!(!map.can_add_column());
+        assert!(map.can_add_row());
+
+        map.add_row("Street 3".to_string());
+
+        assert!(!map.can_grow());
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_row_beyond_max_size() {
+        let mut map = TownMap::empty(2, 1);
+        map.set_max_size(2, 1);
+
+        map.add_row("Street 3".to_string());
+    }
+
+    #[test]
+    fn add_building_on_2_lots() {
+        let id = BuildingId::new(42);
+        let mut map = TownMap::empty(1, 1);
+
+        assert!(!map.is_space_free(0, 1, LotSize::Double));
+        assert!(map.is_space_free(0, 2, LotSize::Double));
+
+        map.add_construction_site(id, 0, 2, LotSize::Double);
+        map.complete_building(id);
+
+        assert_eq!(
+            map.get_block(0, 0),
+            &SmallBuildings([EmptyLot, EmptyLot, BuildingLot(id), BuildingLot(id)])
+        );
+        assert_eq!(map.find_building(id), Some((0, 2)));
+        assert!(!map.is_space_free(0, 0, LotSize::Block));
+
+        map.remove_building(id);
+
+        assert_eq!(map.get_block(0, 0), &TownBlock::empty());
+    }
+
+    #[test]
+    fn add_large_building() {
+        let id = BuildingId::new(42);
+        let mut map = TownMap::empty(2, 1);
+
+        map.add_building(id, 1, 0, LotSize::Block);
+
+        assert_eq!(map.get_block(0, 1), &LargeBuilding(BuildingLot(id)));
+        assert_eq!(map.get_building(1, 3), Some(id));
+        assert_eq!(map.find_building(id), Some((1, 0)));
+        assert!(!map.is_lot_free(1, 1));
+
+        map.remove_building(id);
+
+        assert_eq!(map.get_block(0, 1), &EmptyBlock);
+    }
+
+    #[test]
+    fn nothing_can_be_build_in_a_park() {
+        let mut map = TownMap::empty(2, 1);
+
+        map.set_block(1, Park);
+
+        assert!(!map.is_lot_free(1, 0));
+        assert_eq!(map.get_free_lots(LotSize::Block), vec![(0, 0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn replace_block_with_buildings() {
+        let mut map = TownMap::empty(2, 1);
+        map.add_building(BuildingId::new(42), 1, 0, LotSize::Single);
+
+        map.set_block(1, Water);
+    }
+
+    #[test]
+    fn get_lot_position() {
+        let map = TownMap::empty(3, 2);
+
+        assert_eq!(map.get_lot_position(0, 0), (0, 0));
+        assert_eq!(map.get_lot_position(0, 3), (1, 1));
+        assert_eq!(map.get_lot_position(2, 1), (5, 0));
+        assert_eq!(map.get_lot_position(4, 2), (2, 3));
+    }
+
+    #[test]
+    fn get_address() {
+        let mut map = TownMap::empty(2, 1);
+        map.set_street_names(
+            vec!["A Street".to_string(), "B Street".to_string()],
+            vec![
+                "C Road".to_string(),
+                "D Road".to_string(),
+                "E Road".to_string(),
+            ],
+        );
+
+        assert_eq!(
+            map.get_address(0, 0),
+            Address::new(2, "A Street".to_string())
+        );
+        assert_eq!(
+            map.get_address(0, 1),
+            Address::new(4, "A Street".to_string())
+        );
+        assert_eq!(
+            map.get_address(1, 1),
+            Address::new(8, "A Street".to_string())
+        );
+        assert_eq!(
+            map.get_address(0, 2),
+            Address::new(1, "B Street".to_string())
+        );
+        assert_eq!(
+            map.get_address(1, 3),
+            Address::new(7, "B Street".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_wrong_number_of_street_names() {
+        let mut map = TownMap::empty(2, 1);
+        map.set_street_names(vec!["A Street".to_string()], Vec::new());
+    }
+
+    #[test]
+    fn find_building() {
+        let id0 = BuildingId::new(42);
+        let id1 = BuildingId::new(43);
+        let mut map = TownMap::empty(2, 1);
+
+        map.add_building(id0, 1, 2, LotSize::Single);
+
+        assert_eq!(map.find_building(id0), Some((1, 2)));
+        assert_eq!(map.find_building(id1), None);
+    }
+
+    #[test]
+    fn complete_building() {
+        let id = BuildingId::new(42);
+        let mut map = TownMap::new(2, 1, vec![EmptyBlock, EmptyBlock]);
+
+        map.add_construction_site(id, 1, 3, LotSize::Single);
+
+        assert!(!map.is_lot_free(1, 3));
+        assert_eq!(map.get_building(1, 3), Some(id));
+
+        map.complete_building(id);
+
+        assert_eq!(map.get_building(1, 3), Some(id));
+        assert_eq!(
+            map.get_block(0, 1),
+            &SmallBuildings([EmptyLot, EmptyLot, EmptyLot, BuildingLot(id)])
+        );
+    }
+
+    #[test]
+    fn remove_building() {
+        let id0 = BuildingId::new(42);
+        let id1 = BuildingId::new(43);
+        let block = SmallBuildings([EmptyLot, BuildingLot(id1), BuildingLot(id0), EmptyLot]);
+        let mut map = TownMap::new(2, 1, vec![EmptyBlock, block]);
+
+        map.remove_building(id0);
+
+        assert!(map.is_lot_free(1, 2));
+        assert_eq!(map.get_building(1, 1), Some(id1));
+    }
+
     #[test]
     #[should_panic]
     fn add_second_building_to_a_lots() {
@@ -188,6 +772,6 @@ mod tests {
         let block = SmallBuildings([EmptyLot, EmptyLot, BuildingLot(id0), EmptyLot]);
         let mut map = TownMap::new(2, 1, vec![EmptyBlock, block]);
 
-        map.add_building(id1, 1, 2);
+        map.add_building(id1, 1, 2, LotSize::Single);
     }
 }
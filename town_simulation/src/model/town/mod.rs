@@ -1 +1,15 @@
+use derive_more::Constructor;
+
+pub mod address;
+pub mod district;
 pub mod map;
+pub mod street;
+
+#[derive(Constructor, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TownId(usize);
+
+impl TownId {
+    pub fn id(&self) -> usize {
+        self.0
+    }
+}
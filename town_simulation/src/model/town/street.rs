@@ -0,0 +1,308 @@
+use crate::model::town::map::{TownBlock, TownMap};
+use derive_getters::Getters;
+use derive_more::Constructor;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// The length of the street along a lot in metres.
+pub const LOT_LENGTH: u32 = 20;
+/// The length of the street between 2 intersections in metres.
+pub const SEGMENT_LENGTH: u32 = 2 * LOT_LENGTH;
+
+/// Where a horizontal & a vertical street cross.
+#[derive(Constructor, Getters, Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Intersection {
+    column: usize,
+    row: usize,
+}
+
+/// The intersections along a path & its length in metres.
+#[derive(Constructor, Getters, Clone, Debug, Eq, PartialEq)]
+pub struct Path {
+    intersections: Vec<Intersection>,
+    length: u32,
+}
+
+/// The graph of the walkable streets, which is derived from a [`TownMap`].
+/// Streets along the edge of the map are missing next to water.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreetGraph {
+    positions: Vec<(usize, usize)>,
+    edges: HashMap<Intersection, Vec<Intersection>>,
+}
+
+impl StreetGraph {
+    pub fn new(map: &TownMap) -> Self {
+        let width = *map.width();
+        let height = *map.height();
+        let mut graph = StreetGraph {
+            positions: map.positions().clone(),
+            edges: HashMap::new(),
+        };
+
+        for row in 0..=height {
+            for column in 0..width {
+                let is_edge = row == 0 || row == height;
+                let block_row = if row == 0 { 0 } else { row - 1 };
+
+                if !is_edge || !matches!(map.get_block(block_row, column), TownBlock::Water) {
+                    graph.add_street(
+                        Intersection::new(column, row),
+                        Intersection::new(column + 1, row),
+                    );
+                }
+            }
+        }
+
+        for column in 0..=width {
+            for row in 0..height {
+                let is_edge = column == 0 || column == width;
+                let block_column = if column == 0 { 0 } else { column - 1 };
+
+                if !is_edge || !matches!(map.get_block(row, block_column), TownBlock::Water) {
+                    graph.add_street(
+                        Intersection::new(column, row),
+                        Intersection::new(column, row + 1),
+                    );
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn add_street(&mut self, a: Intersection, b: Intersection) {
+        self.edges.entry(a).or_default().push(b);
+        self.edges.entry(b).or_default().push(a);
+    }
+
+    pub fn get_neighbours(&self, intersection: Intersection) -> &[Intersection] {
+        self.edges
+            .get(&intersection)
+            .map(|neighbours| neighbours.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn find_path(&self, from: Intersection, to: Intersection) -> Option<Path> {
+        self.find_shortest_path(&[(from, 0)], &[(to, 0)])
+    }
+
+    /// Finds the shortest path between the fronts of 2 lots, which are given as block & lot.
+    pub fn find_path_between_lots(&self, from: (usize, usize), to: (usize, usize)) -> Option<Path> {
+        let (from_street, from_offset) = self.get_street_of_lot(from.0, from.1);
+        let (to_street, to_offset) = self.get_street_of_lot(to.0, to.1);
+
+        if from_street == to_street {
+            return Some(Path::new(Vec::new(), from_offset.abs_diff(to_offset)));
+        }
+
+        self.find_shortest_path(
+            &self.get_entrances(from_street, from_offset),
+            &self.get_entrances(to_street, to_offset),
+        )
+    }
+
+    /// Returns the walking distance in metres between 2 lots, which are given as block & lot.
+    pub fn get_walking_distance(&self, from: (usize, usize), to: (usize, usize)) -> Option<u32> {
+        self.find_path_between_lots(from, to)
+            .map(|path| path.length)
+    }
+
+    /// Returns the walking distances from one lot to several lots with a single search.
+    pub fn get_walking_distances(
+        &self,
+        from: (usize, usize),
+        to: &[(usize, usize)],
+    ) -> Vec<Option<u32>> {
+        let (from_street, from_offset) = self.get_street_of_lot(from.0, from.1);
+        let (distances, _) = self.search(&self.get_entrances(from_street, from_offset));
+
+        to.iter()
+            .map(|(block, lot)| {
+                let (street, offset) = self.get_street_of_lot(*block, *lot);
+
+                if street == from_street {
+                    return Some(from_offset.abs_diff(offset));
+                }
+
+                self.get_entrances(street, offset)
+                    .iter()
+                    .filter_map(|(goal, cost)| distances.get(goal).map(|distance| distance + cost))
+                    .min()
+            })
+            .collect()
+    }
+
+    /// Returns the western intersection of the street segment in front of the lot
+    /// & the distance from it.
+    fn get_street_of_lot(&self, block: usize, lot: usize) -> (Intersection, u32) {
+        let (column, row) = self.positions[block];
+        let row = row + lot / 2;
+        let offset = (lot % 2) as u32 * LOT_LENGTH + LOT_LENGTH / 2;
+
+        (Intersection::new(column, row), offset)
+    }
+
+    fn get_entrances(&self, west: Intersection, offset: u32) -> Vec<(Intersection, u32)> {
+        let east = Intersection::new(west.column + 1, west.row);
+        let mut entrances = Vec::new();
+
+        if self.get_neighbours(west).contains(&east) {
+            entrances.push((west, offset));
+            entrances.push((east, SEGMENT_LENGTH - offset));
+        }
+
+        entrances
+    }
+
+    /// Dijkstra's algorithm from several starts with initial costs to several goals with final costs.
+    fn find_shortest_path(
+        &self,
+        starts: &[(Intersection, u32)],
+        goals: &[(Intersection, u32)],
+    ) -> Option<Path> {
+        let (distances, previous) = self.search(starts);
+        let (goal, length) = goals
+            .iter()
+            .filter_map(|(goal, cost)| distances.get(goal).map(|distance| (*goal, distance + cost)))
+            .min_by_key(|(_, length)| *length)?;
+        let mut intersections = vec![goal];
+
+        while let Some(intersection) = previous.get(intersections.last().unwrap()) {
+            intersections.push(*intersection);
+        }
+
+        intersections.reverse();
+
+        Some(Path::new(intersections, length))
+    }
+
+    /// Returns the distances to all reachable intersections & their previous intersections.
+    fn search(
+        &self,
+        starts: &[(Intersection, u32)],
+    ) -> (
+        HashMap<Intersection, u32>,
+        HashMap<Intersection, Intersection>,
+    ) {
+        let mut distances: HashMap<Intersection, u32> = HashMap::new();
+        let mut previous: HashMap<Intersection, Intersection> = HashMap::new();
+        let mut queue = BinaryHeap::new();
+
+        for (start, cost) in starts {
+            if distances.get(start).is_none_or(|distance| cost < distance) {
+                distances.insert(*start, *cost);
+                queue.push(Reverse((*cost, *start)));
+            }
+        }
+
+        while let Some(Reverse((distance, intersection))) = queue.pop() {
+            if distances[&intersection] < distance {
+                continue;
+            }
+
+            for neighbour in self.get_neighbours(intersection) {
+                let new_distance = distance + SEGMENT_LENGTH;
+
+                if distances
+                    .get(neighbour)
+                    .is_none_or(|old_distance| new_distance < *old_distance)
+                {
+                    distances.insert(*neighbour, new_distance);
+                    previous.insert(*neighbour, intersection);
+                    queue.push(Reverse((new_distance, *neighbour)));
+                }
+            }
+        }
+
+        (distances, previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::town::map::TownBlock::{EmptyBlock, Water};
+
+    #[test]
+    fn test_find_path() {
+        let graph = StreetGraph::new(&TownMap::empty(2, 2));
+
+        let path = graph
+            .find_path(Intersection::new(0, 0), Intersection::new(2, 1))
+            .unwrap();
+
+        assert_eq!(path.length, 3 * SEGMENT_LENGTH);
+        assert_eq!(path.intersections.len(), 4);
+        assert_eq!(path.intersections[0], Intersection::new(0, 0));
+        assert_eq!(path.intersections[3], Intersection::new(2, 1));
+    }
+
+    #[test]
+    fn test_find_path_to_itself() {
+        let graph = StreetGraph::new(&TownMap::empty(2, 2));
+        let intersection = Intersection::new(1, 1);
+
+        assert_eq!(
+            graph.find_path(intersection, intersection),
+            Some(Path::new(vec![intersection], 0))
+        );
+    }
+
+    #[test]
+    fn test_no_streets_along_the_coast() {
+        let map = TownMap::new(2, 1, vec![Water, EmptyBlock]);
+        let graph = StreetGraph::new(&map);
+
+        assert!(graph.get_neighbours(Intersection::new(0, 0)).is_empty());
+        assert_eq!(
+            graph.find_path(Intersection::new(0, 0), Intersection::new(2, 0)),
+            None
+        );
+        assert_eq!(
+            graph.get_neighbours(Intersection::new(1, 0)),
+            &[Intersection::new(2, 0), Intersection::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_walking_distance_along_the_same_street() {
+        let graph = StreetGraph::new(&TownMap::empty(2, 2));
+
+        assert_eq!(graph.get_walking_distance((0, 0), (0, 0)), Some(0));
+        assert_eq!(graph.get_walking_distance((0, 0), (0, 1)), Some(LOT_LENGTH));
+        // the lots are on opposite sides of the same street
+        assert_eq!(graph.get_walking_distance((0, 3), (2, 1)), Some(0));
+    }
+
+    #[test]
+    fn test_walking_distances() {
+        let map = TownMap::new(2, 2, vec![Water, EmptyBlock, EmptyBlock, EmptyBlock]);
+        let graph = StreetGraph::new(&map);
+        let lots: Vec<(usize, usize)> = (0..4)
+            .flat_map(|block| (0..4).map(move |lot| (block, lot)))
+            .collect();
+
+        for from in &lots {
+            let expected: Vec<Option<u32>> = lots
+                .iter()
+                .map(|to| graph.get_walking_distance(*from, *to))
+                .collect();
+
+            assert_eq!(graph.get_walking_distances(*from, &lots), expected);
+        }
+    }
+
+    #[test]
+    fn test_walking_distance_between_streets() {
+        let graph = StreetGraph::new(&TownMap::empty(2, 2));
+
+        // from lot 1 of block 0 east to the intersection & along the street of block 1
+        assert_eq!(graph.get_walking_distance((0, 1), (1, 0)), Some(LOT_LENGTH));
+        // from lot 0 of block 0 to the north-western intersection, south & east to lot 2
+        assert_eq!(
+            graph.get_walking_distance((0, 0), (0, 2)),
+            Some(LOT_LENGTH / 2 + SEGMENT_LENGTH + LOT_LENGTH / 2)
+        );
+    }
+}
@@ -1,54 +1,429 @@
+use crate::generation::number::RandomNumberGenerator;
+use crate::model::building::owner::Owner;
 use crate::model::building::usage::BuildingUsage;
 use crate::model::building::BuildingId;
 use crate::model::character::CharacterId;
-use crate::usecase::building::build::build;
-use crate::usecase::building::relocate::relocate_to_house;
+use crate::usecase::building::build::{
+    complete_construction, get_finished_construction_sites, start_construction,
+};
+use crate::usecase::building::condition::{decay, demolish, get_condition, renovate};
+use crate::usecase::building::occupancy::{get_building_occupied_by, get_occupants};
+use crate::usecase::building::ownership::{
+    get_abandoned_buildings, get_buildings_owned_by, revert_to_town, update_owner,
+};
+use crate::usecase::building::relocate::{join_home, relocate_to_house};
+use crate::usecase::character::emigration::emigrate;
+use crate::usecase::character::marriage::is_married;
+use crate::usecase::character::relation::get::{get_children, get_spouses};
+use crate::usecase::character::wealth::get_household_wealth;
+use crate::usecase::town::{find_best_lot, grow_map};
 use crate::SimulationData;
 
+const AUCTION: u32 = 5;
+const RENOVATION: u32 = 6;
+const AUCTION_BUYER: u32 = 8;
+const APARTMENT_HOMES: usize = 4;
+const BASE_BUDGET: u32 = 60;
+
+/// Moves the household into an empty home or starts the construction of a new house
+/// on the best lot for the zoning & the wealth of the household. During the construction the household stays together in the home of one of its members.
+/// If nobody of them has a home, the household waits as the workers of the construction site.
+/// Once the map reached its maximum size, apartments are built instead.
+/// Without a free lot the household stays together or emigrates, if nobody has a home.
 pub fn relocate(data: &mut SimulationData, character_ids: Vec<CharacterId>) {
-    let building_id = find_or_build_new_home(data, &character_ids);
+    if let Some(building_id) = find_empty_home(data) {
+        relocate_to_house(data, character_ids, building_id);
+        return;
+    }
+
+    let usage = if data.map.can_grow() {
+        BuildingUsage::house()
+    } else {
+        BuildingUsage::apartments(APARTMENT_HOMES)
+    };
+    let budget = BASE_BUDGET + get_household_wealth(data, &character_ids);
+
+    if let Some((block, lot)) = find_best_location(data, &usage, budget) {
+        let builder = character_ids[0];
 
-    relocate_to_house(data, character_ids, building_id);
+        start_construction(
+            data,
+            block,
+            lot,
+            usage,
+            builder,
+            builder,
+            character_ids.clone(),
+        );
+
+        stay_together(data, character_ids);
+    } else if !stay_together(data, character_ids.clone()) {
+        let ids: Vec<usize> = character_ids.iter().map(|id| id.id()).collect();
+        println!("Characters {:?} emigrate", ids);
+        emigrate(data, character_ids);
+    }
 }
 
-fn find_or_build_new_home(data: &mut SimulationData, character_ids: &[CharacterId]) -> BuildingId {
-    if let Some(building_id) = find_empty_home(data) {
-        return building_id;
+/// Returns false, if nobody of the household has a home.
+fn stay_together(data: &mut SimulationData, character_ids: Vec<CharacterId>) -> bool {
+    if let Some(&host) = character_ids
+        .iter()
+        .find(|&id| get_building_occupied_by(&data.character_manager, *id).is_some())
+    {
+        let guests = character_ids.into_iter().filter(|id| *id != host).collect();
+        join_home(data, guests, host);
+        return true;
+    }
+
+    false
+}
+
+/// Completes finished construction sites & the household of the builder or owner moves in
+/// together with the homeless workers.
+pub fn simulate_construction(data: &mut SimulationData) {
+    for building_id in get_finished_construction_sites(data) {
+        println!("Building {} is completed", building_id.id());
+        complete_construction(data, building_id);
+
+        let mut household = get_household(data, building_id);
+        let workers: Vec<CharacterId> = get_homeless_workers(data, building_id)
+            .into_iter()
+            .filter(|id| !household.contains(id))
+            .collect();
+        household.extend(workers);
+
+        if !household.is_empty() {
+            relocate_to_house(data, household, building_id);
+        }
+    }
+}
+
+/// Returns the resident builder or owner with their spouse & minor children.
+fn get_household(data: &SimulationData, building_id: BuildingId) -> Vec<CharacterId> {
+    let adult_age = 18;
+    let manager = &data.character_manager;
+    let building = data.building_manager.get(building_id).unwrap();
+    let mut candidates = vec![*building.builder()];
+    candidates.extend(building.owner().get_characters());
+
+    if let Some(&head) = candidates
+        .iter()
+        .find(|&id| manager.get(*id).unwrap().lives_in(data.town))
+    {
+        let mut household = vec![head];
+        let mut spouses: Vec<CharacterId> = get_spouses(manager, head).into_iter().collect();
+        let mut children: Vec<CharacterId> = get_children(manager, head)
+            .into_iter()
+            .filter(|id| {
+                let child = manager.get(*id).unwrap();
+                child.get_age(data.date) < adult_age && !is_married(manager, *id)
+            })
+            .collect();
+        sort_by_age(data, &mut spouses);
+        sort_by_age(data, &mut children);
+        household.extend(spouses);
+        household.extend(children);
+
+        household
+            .into_iter()
+            .filter(|id| manager.get(*id).unwrap().lives_in(data.town))
+            .collect()
+    } else {
+        Vec::new()
     }
+}
 
-    let (block, lot) = find_best_location(data);
-    let builder = character_ids[0];
+fn get_homeless_workers(data: &SimulationData, building_id: BuildingId) -> Vec<CharacterId> {
+    let manager = &data.character_manager;
 
-    build(data, block, lot, BuildingUsage::house(), builder, builder)
+    data.building_manager
+        .get(building_id)
+        .unwrap()
+        .workers()
+        .iter()
+        .copied()
+        .filter(|id| {
+            manager.get(*id).unwrap().lives_in(data.town)
+                && get_building_occupied_by(manager, *id).is_none()
+        })
+        .collect()
 }
 
 fn find_empty_home(data: &SimulationData) -> Option<BuildingId> {
-    for building in data.building_manager.get_all() {
-        match building.usage() {
-            BuildingUsage::Apartments(homes) => {
-                if homes.iter().any(|home| home.is_empty()) {
-                    return Some(*building.id());
-                }
+    data.building_manager
+        .get_all()
+        .iter()
+        .filter(|building| !building.is_demolished() && !building.is_under_construction())
+        .find(|building| building.usage().has_empty_home())
+        .map(|building| *building.id())
+}
+
+/// Returns the best lot for the usage & budget. The map grows, until such a lot is found.
+fn find_best_location(
+    data: &mut SimulationData,
+    usage: &BuildingUsage,
+    budget: u32,
+) -> Option<(usize, usize)> {
+    loop {
+        if let Some(location) = find_best_lot(&data.map, usage, budget) {
+            return Some(location);
+        }
+
+        if !grow_map(&mut data.map, &data.street_name_generator) {
+            return None;
+        }
+
+        println!(
+            "The town grows to {}x{} blocks",
+            data.map.width(),
+            data.map.height()
+        );
+    }
+}
+
+/// Buildings decay faster with age & vacancy. Owners may renovate them & they collapse at 0.
+pub fn simulate_building_condition(data: &mut SimulationData, rng: &RandomNumberGenerator) {
+    let renovation_threshold = 50;
+    let chance_of_renovation = 30;
+    let buildings: Vec<BuildingId> = data
+        .building_manager
+        .get_all()
+        .iter()
+        .filter(|building| !building.is_demolished() && !building.is_under_construction())
+        .map(|building| *building.id())
+        .collect();
+
+    for building_id in buildings {
+        let building = data.building_manager.get(building_id).unwrap();
+        let is_vacant = get_occupants(&data.building_manager, building_id).is_empty();
+        let has_owner = building.usage().is_public()
+            || building.owner().get_characters().iter().any(|owner| {
+                data.character_manager
+                    .get(*owner)
+                    .unwrap()
+                    .lives_in(data.town)
+            });
+        let value = 1 + building.get_age(data.date) / 50 + if is_vacant { 2 } else { 0 };
+
+        decay(&mut data.building_manager, building_id, value);
+
+        let condition = get_condition(&data.building_manager, building_id);
+
+        if condition == 0 {
+            println!("Building {} collapses", building_id.id());
+            let households: Vec<Vec<CharacterId>> = data
+                .building_manager
+                .get(building_id)
+                .unwrap()
+                .usage()
+                .get_homes()
+                .into_iter()
+                .filter(|home| !home.is_empty())
+                .map(|home| home.occupants().clone())
+                .collect();
+            demolish(data, building_id);
+
+            for mut household in households {
+                sort_by_age(data, &mut household);
+                relocate(data, household);
             }
-            BuildingUsage::House(home) => {
-                if home.is_empty() {
-                    return Some(*building.id());
-                }
+        } else if has_owner && condition < renovation_threshold {
+            let roll = rng.roll_d100(data.date.get_year(), building_id.id(), RENOVATION);
+
+            if roll < chance_of_renovation {
+                println!("Building {} is renovated", building_id.id());
+                renovate(data, building_id);
             }
         }
     }
-
-    None
 }
 
-fn find_best_location(data: &SimulationData) -> (usize, usize) {
-    for block in 0..data.map.blocks().len() {
-        for lot in 0..4 {
-            if data.map.is_lot_free(block, lot) {
-                return (block, lot);
-            }
+/// Abandoned buildings revert to the town, which gives them to their occupants or auctions them.
+pub fn simulate_abandoned_buildings(data: &mut SimulationData, rng: &RandomNumberGenerator) {
+    let adult_age = 18;
+    let chance_of_auction = 50;
+
+    for building_id in get_abandoned_buildings(data) {
+        revert_to_town(data, building_id);
+
+        let occupants = get_adults(
+            data,
+            get_occupants(&data.building_manager, building_id),
+            adult_age,
+        );
+
+        if let Some(occupant) = occupants.first() {
+            println!(
+                "Building {} is given to its occupant {}",
+                building_id.id(),
+                occupant.id()
+            );
+            update_owner(data, building_id, Owner::Character(*occupant));
+            continue;
+        }
+
+        let roll = rng.roll_d100(data.date.get_year(), building_id.id(), AUCTION);
+
+        if roll >= chance_of_auction {
+            continue;
+        }
+
+        let bidders = get_bidders(data, adult_age);
+
+        if !bidders.is_empty() {
+            let buyer = bidders[rng.roll_index(
+                data.date.get_year(),
+                building_id.id(),
+                AUCTION_BUYER,
+                bidders.len(),
+            )];
+            println!(
+                "Building {} is auctioned to {}",
+                building_id.id(),
+                buyer.id()
+            );
+            update_owner(data, building_id, Owner::Character(buyer));
         }
     }
+}
+
+fn get_adults(
+    data: &SimulationData,
+    ids: impl IntoIterator<Item = CharacterId>,
+    adult_age: u32,
+) -> Vec<CharacterId> {
+    let mut adults: Vec<CharacterId> = ids
+        .into_iter()
+        .filter(|id| {
+            let character = data.character_manager.get(*id).unwrap();
+            character.lives_in(data.town) && character.get_age(data.date) >= adult_age
+        })
+        .collect();
+
+    sort_by_age(data, &mut adults);
+
+    adults
+}
+
+fn sort_by_age(data: &SimulationData, ids: &mut [CharacterId]) {
+    ids.sort_by_key(|id| {
+        let character = data.character_manager.get(*id).unwrap();
+        (character.birth_date().get_year(), id.id())
+    });
+}
 
-    (0, 0)
+/// Adults without property can bid at an auction.
+fn get_bidders(data: &SimulationData, adult_age: u32) -> Vec<CharacterId> {
+    let candidates = data
+        .character_manager
+        .get_all()
+        .iter()
+        .map(|character| *character.id())
+        .filter(|id| get_buildings_owned_by(&data.character_manager, *id).is_empty());
+
+    get_adults(data, candidates, adult_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::time::Date;
+    use crate::model::town::map::TownMap;
+    use crate::usecase::building::build::build;
+    use crate::util::assert::assert;
+    use std::collections::HashMap;
+
+    #[test]
+    fn households_of_collapsed_building_move_separately() {
+        let mut data = SimulationData {
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let manager = &mut data.character_manager;
+        let [a0, a1, b0, b1] = [(); 4].map(|_| manager.create());
+        let apartments = build(&mut data, 4, 0, BuildingUsage::apartments(2), a0, a0);
+        let house0 = build(&mut data, 0, 0, BuildingUsage::house(), a0, a0);
+        let house1 = build(&mut data, 2, 0, BuildingUsage::house(), b0, b0);
+        relocate_to_house(&mut data, vec![a0, a1], apartments);
+        relocate_to_house(&mut data, vec![b0, b1], apartments);
+        decay(&mut data.building_manager, apartments, 99);
+
+        simulate_building_condition(&mut data, &RandomNumberGenerator::Hash);
+
+        assert!(data
+            .building_manager
+            .get(apartments)
+            .unwrap()
+            .is_demolished());
+        assert(get_occupants(&data.building_manager, house0), [a0, a1]);
+        assert(get_occupants(&data.building_manager, house1), [b0, b1]);
+    }
+
+    #[test]
+    fn auction_to_one_of_many_bidders() {
+        let mut data = SimulationData {
+            date: Date::new(30),
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let bidders: Vec<CharacterId> = (0..60).map(|_| data.character_manager.create()).collect();
+        let house = build(
+            &mut data,
+            0,
+            0,
+            BuildingUsage::house(),
+            bidders[0],
+            bidders[0],
+        );
+        update_owner(&mut data, house, Owner::Town);
+        let rng = RandomNumberGenerator::Mock {
+            values: HashMap::from([
+                ((30, house.id(), AUCTION), 10),
+                ((30, house.id(), AUCTION_BUYER), 55),
+            ]),
+            default: 99,
+        };
+
+        simulate_abandoned_buildings(&mut data, &rng);
+
+        assert_eq!(
+            data.building_manager.get(house).unwrap().owner(),
+            &Owner::Character(bidders[55])
+        );
+    }
+
+    #[test]
+    fn homeless_household_moves_into_its_construction_site() {
+        let mut data = SimulationData {
+            date: Date::new(30),
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let manager = &mut data.character_manager;
+        let [parent, child, grandparent] = [(); 3].map(|_| manager.create());
+        let house = build(&mut data, 0, 0, BuildingUsage::house(), parent, parent);
+        relocate_to_house(&mut data, vec![parent, child, grandparent], house);
+        decay(&mut data.building_manager, house, 99);
+
+        simulate_building_condition(&mut data, &RandomNumberGenerator::Hash);
+
+        let site = *data.building_manager.get_all().last().unwrap().id();
+        assert!(data
+            .building_manager
+            .get(site)
+            .unwrap()
+            .is_under_construction());
+        assert_eq!(
+            get_building_occupied_by(&data.character_manager, parent),
+            None
+        );
+
+        data.date.increase_by(2);
+        simulate_construction(&mut data);
+
+        assert(
+            get_occupants(&data.building_manager, site),
+            [parent, child, grandparent],
+        );
+    }
 }
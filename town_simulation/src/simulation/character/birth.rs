@@ -23,13 +23,13 @@ fn calculate_expecting(
     for (id0, id1) in get_married_couples(&data.character_manager) {
         let character0 = data.character_manager.get(id0).unwrap();
 
-        if character0.is_dead() {
+        if !character0.lives_in(data.town) {
             continue;
         }
 
         let character1 = data.character_manager.get(id1).unwrap();
 
-        if character1.is_dead() {
+        if !character1.lives_in(data.town) {
             continue;
         }
 
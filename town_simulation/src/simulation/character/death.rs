@@ -19,7 +19,7 @@ fn calculate_dying(
     let mut dying = Vec::new();
 
     for character in data.character_manager.get_all() {
-        if character.is_dead() {
+        if !character.lives_in(data.town) {
             continue;
         }
 
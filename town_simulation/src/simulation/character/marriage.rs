@@ -1,9 +1,13 @@
 use crate::generation::number::RandomNumberGenerator;
+use crate::model::building::BuildingId;
 use crate::model::character::gender::Gender::Male;
-use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::character::CharacterId;
+use crate::model::town::street::StreetGraph;
 use crate::simulation::building::relocate;
+use crate::usecase::building::distance::get_walking_distances;
+use crate::usecase::building::occupancy::get_building_occupied_by;
 use crate::usecase::character::get_gender;
-use crate::usecase::character::marriage::{get_unmarried, marry};
+use crate::usecase::character::marriage::{get_unmarried, wed};
 use crate::usecase::character::relation::get::get_relatives;
 use crate::SimulationData;
 use std::collections::HashSet;
@@ -22,30 +26,37 @@ pub fn simulate_marriage(data: &mut SimulationData, rng: &RandomNumberGenerator)
         .copied()
         .collect();
 
+    let mut graph = StreetGraph::new(&data.map);
+
     for id in selected_characters {
-        if let Some(spouse) = select_spouse(&data.character_manager, id, &remaining) {
+        if let Some(spouse) = select_spouse(data, &graph, id, &remaining) {
             let (husband, wife) = if get_gender(&data.character_manager, id) == Male {
                 (id, spouse)
             } else {
                 (spouse, id)
             };
 
-            marry(&mut data.character_manager, husband, wife);
+            wed(data, husband, wife);
             // TODO: ownership?
+            let blocks = data.map.blocks().len();
             relocate(data, vec![husband, wife]);
 
+            if data.map.blocks().len() != blocks {
+                graph = StreetGraph::new(&data.map);
+            }
+
             remaining.remove(&spouse);
         }
     }
 }
 
-fn get_marriageable(data: &SimulationData, min_age: u32, max_age: u32) -> HashSet<CharacterId> {
+pub fn get_marriageable(data: &SimulationData, min_age: u32, max_age: u32) -> HashSet<CharacterId> {
     let mut marriageable = HashSet::new();
 
     for id in get_unmarried(&data.character_manager) {
         let character = data.character_manager.get(id).unwrap();
 
-        if character.is_dead() {
+        if !character.lives_in(data.town) {
             continue;
         }
 
@@ -89,19 +100,53 @@ fn select_characters_to_marry(
     selected
 }
 
+/// Selects the candidate living closest to the character.
 fn select_spouse(
-    manager: &CharacterMgr,
+    data: &SimulationData,
+    graph: &StreetGraph,
     id: CharacterId,
     candidates: &HashSet<CharacterId>,
 ) -> Option<CharacterId> {
+    let manager = &data.character_manager;
     let relatives = get_relatives(manager, id);
     let character = manager.get(id).unwrap();
-
-    candidates
+    let candidates: Vec<CharacterId> = candidates
         .iter()
         .filter(|&candidate_id| !relatives.contains(candidate_id))
         .map(|&candidate_id| manager.get(candidate_id).unwrap())
         .filter(|&candidate| character.gender().is_reverse(*candidate.gender()))
         .map(|candidate| *candidate.id())
-        .next()
+        .collect();
+    let distances = get_distances_to_homes(data, graph, id, &candidates);
+
+    candidates
+        .into_iter()
+        .zip(distances)
+        .min_by_key(|(candidate, distance)| (distance.unwrap_or(u32::MAX), candidate.id()))
+        .map(|(candidate, _)| candidate)
+}
+
+/// Returns the walking distances from the home of the character to the homes of the others.
+/// The distance is none, if one of them is homeless.
+fn get_distances_to_homes(
+    data: &SimulationData,
+    graph: &StreetGraph,
+    id: CharacterId,
+    others: &[CharacterId],
+) -> Vec<Option<u32>> {
+    let manager = &data.character_manager;
+    let Some(home) = get_building_occupied_by(manager, id) else {
+        return vec![None; others.len()];
+    };
+    let homes: Vec<Option<BuildingId>> = others
+        .iter()
+        .map(|other| get_building_occupied_by(manager, *other))
+        .collect();
+    let buildings: Vec<BuildingId> = homes.iter().flatten().copied().collect();
+    let mut distances = get_walking_distances(data, graph, home, &buildings).into_iter();
+
+    homes
+        .into_iter()
+        .map(|home| home.and_then(|_| distances.next().flatten()))
+        .collect()
 }
@@ -1,3 +1,4 @@
 pub mod birth;
 pub mod death;
 pub mod marriage;
+pub mod orphan;
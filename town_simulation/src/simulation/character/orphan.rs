@@ -0,0 +1,36 @@
+use crate::generation::number::RandomNumberGenerator;
+use crate::model::character::CharacterId;
+use crate::usecase::character::guardianship::{
+    adopt, get_guardian, has_living_guardian, is_orphan, take_in,
+};
+use crate::SimulationData;
+
+const ADOPTION: u32 = 4;
+
+pub fn simulate_orphan_care(data: &mut SimulationData, rng: &RandomNumberGenerator) {
+    let adult_age = 18;
+    let chance_of_adoption = 30;
+
+    for orphan in get_orphans_without_guardian(data, adult_age - 1) {
+        if let Some(guardian) = get_guardian(data, orphan, adult_age) {
+            take_in(data, guardian, orphan);
+
+            let roll = rng.roll_d100(data.date.get_year(), orphan.id(), ADOPTION);
+
+            if roll < chance_of_adoption {
+                println!("Character {} adopts {}", guardian.id(), orphan.id());
+                adopt(&mut data.character_manager, guardian, orphan);
+            }
+        }
+    }
+}
+
+fn get_orphans_without_guardian(data: &SimulationData, max_age: u32) -> Vec<CharacterId> {
+    data.character_manager
+        .get_all()
+        .iter()
+        .map(|character| *character.id())
+        .filter(|id| is_orphan(data, *id, max_age))
+        .filter(|id| !has_living_guardian(&data.character_manager, *id))
+        .collect()
+}
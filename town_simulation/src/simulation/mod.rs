@@ -1,20 +1,13 @@
-use crate::generation::number::RandomNumberGenerator;
-use crate::simulation::character::birth::simulate_birth;
-use crate::simulation::character::death::simulate_death;
-use crate::simulation::character::marriage::simulate_marriage;
+use crate::simulation::pipeline::SimulationPipeline;
 use crate::SimulationData;
 
 pub mod building;
 pub mod character;
+pub mod observer;
+pub mod pipeline;
+pub mod run;
+pub mod world;
 
 pub fn simulate_year(data: &mut SimulationData) {
-    println!("Simulate year {}", data.date.get_year());
-
-    let rng = RandomNumberGenerator::Hash;
-
-    simulate_marriage(data, &rng);
-    simulate_birth(data, &rng);
-    simulate_death(data, &rng);
-
-    data.date.increase_year();
+    SimulationPipeline::default().simulate_year(data);
 }
@@ -0,0 +1,31 @@
+use crate::model::event::EventRecord;
+use std::sync::mpsc::Sender;
+
+pub trait SimulationObserver {
+    fn notify(&mut self, event: &EventRecord);
+}
+
+impl<F: FnMut(&EventRecord)> SimulationObserver for F {
+    fn notify(&mut self, event: &EventRecord) {
+        self(event)
+    }
+}
+
+/// Sends the events through a channel, e.g. to another thread.
+#[derive(Clone, Debug)]
+pub struct ChannelObserver {
+    sender: Sender<EventRecord>,
+}
+
+impl ChannelObserver {
+    pub fn new(sender: Sender<EventRecord>) -> Self {
+        Self { sender }
+    }
+}
+
+impl SimulationObserver for ChannelObserver {
+    fn notify(&mut self, event: &EventRecord) {
+        // the simulation continues, if the receiver was dropped
+        let _ = self.sender.send(event.clone());
+    }
+}
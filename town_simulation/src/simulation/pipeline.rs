@@ -0,0 +1,265 @@
+use crate::generation::number::RandomNumberGenerator;
+use crate::simulation::building::{
+    simulate_abandoned_buildings, simulate_building_condition, simulate_construction,
+};
+use crate::simulation::character::birth::simulate_birth;
+use crate::simulation::character::death::simulate_death;
+use crate::simulation::character::marriage::simulate_marriage;
+use crate::simulation::character::orphan::simulate_orphan_care;
+use crate::simulation::observer::SimulationObserver;
+use crate::usecase::event::take_events;
+use crate::SimulationData;
+use derive_getters::Getters;
+use derive_more::Constructor;
+
+/// A step of the yearly simulation of a town.
+pub trait SimulationSystem {
+    /// The unique name of the system in a pipeline.
+    fn get_name(&self) -> &str;
+
+    fn run(&self, data: &mut SimulationData, rng: &RandomNumberGenerator);
+}
+
+#[derive(Constructor, Getters, Clone, Debug)]
+pub struct FunctionSystem {
+    name: &'static str,
+    function: fn(&mut SimulationData, &RandomNumberGenerator),
+}
+
+impl SimulationSystem for FunctionSystem {
+    fn get_name(&self) -> &str {
+        self.name
+    }
+
+    fn run(&self, data: &mut SimulationData, rng: &RandomNumberGenerator) {
+        (self.function)(data, rng)
+    }
+}
+
+/// Runs the systems in order with the same random number generator
+/// & dispatches the published events to the observers after each system.
+#[derive(Getters)]
+pub struct SimulationPipeline {
+    rng: RandomNumberGenerator,
+    systems: Vec<Box<dyn SimulationSystem>>,
+    observers: Vec<Box<dyn SimulationObserver>>,
+}
+
+impl SimulationPipeline {
+    pub fn empty(rng: RandomNumberGenerator) -> Self {
+        Self {
+            rng,
+            systems: Vec::new(),
+            observers: Vec::new(),
+        }
+    }
+
+    pub fn new(rng: RandomNumberGenerator) -> Self {
+        let mut pipeline = Self::empty(rng);
+
+        pipeline.add(FunctionSystem::new("construction", |data, _| {
+            simulate_construction(data)
+        }));
+        pipeline.add(FunctionSystem::new("marriage", simulate_marriage));
+        pipeline.add(FunctionSystem::new("birth", simulate_birth));
+        pipeline.add(FunctionSystem::new("death", simulate_death));
+        pipeline.add(FunctionSystem::new("orphan_care", simulate_orphan_care));
+        pipeline.add(FunctionSystem::new(
+            "abandoned_buildings",
+            simulate_abandoned_buildings,
+        ));
+        pipeline.add(FunctionSystem::new(
+            "building_condition",
+            simulate_building_condition,
+        ));
+
+        pipeline
+    }
+
+    pub fn set_rng(&mut self, rng: RandomNumberGenerator) {
+        self.rng = rng;
+    }
+
+    pub fn get_names(&self) -> Vec<&str> {
+        self.systems
+            .iter()
+            .map(|system| system.get_name())
+            .collect()
+    }
+
+    fn get_index(&self, name: &str) -> Option<usize> {
+        self.systems
+            .iter()
+            .position(|system| system.get_name() == name)
+    }
+
+    pub fn add<S: SimulationSystem + 'static>(&mut self, system: S) {
+        self.systems.push(Box::new(system));
+    }
+
+    /// Inserts the system before another one or at the end, if the other one is unknown.
+    pub fn insert_before<S: SimulationSystem + 'static>(&mut self, name: &str, system: S) {
+        let index = self.get_index(name).unwrap_or(self.systems.len());
+        self.systems.insert(index, Box::new(system));
+    }
+
+    /// Removes the system & returns it, so it can be inserted somewhere else.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn SimulationSystem>> {
+        self.get_index(name).map(|index| self.systems.remove(index))
+    }
+
+    /// Moves a system before another one. Returns false, if the system is unknown.
+    pub fn move_before(&mut self, name: &str, other: &str) -> bool {
+        if let Some(system) = self.remove(name) {
+            let index = self.get_index(other).unwrap_or(self.systems.len());
+            self.systems.insert(index, system);
+            return true;
+        }
+
+        false
+    }
+
+    pub fn subscribe<O: SimulationObserver + 'static>(&mut self, observer: O) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Notifies the observers about all events published since the last dispatch.
+    pub fn dispatch_events(&mut self, data: &mut SimulationData) {
+        for event in take_events(data) {
+            for observer in &mut self.observers {
+                observer.notify(&event);
+            }
+        }
+    }
+
+    /// Simulates the selected town without advancing the date.
+    pub fn simulate_town(&mut self, data: &mut SimulationData) {
+        self.dispatch_events(data);
+
+        for index in 0..self.systems.len() {
+            self.systems[index].run(data, &self.rng);
+            self.dispatch_events(data);
+        }
+    }
+
+    pub fn simulate_year(&mut self, data: &mut SimulationData) {
+        println!("Simulate year {}", data.date.get_year());
+
+        self.simulate_town(data);
+
+        data.date.increase_year();
+    }
+}
+
+impl Default for SimulationPipeline {
+    fn default() -> Self {
+        Self::new(RandomNumberGenerator::Hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::CharacterId;
+    use crate::model::event::{EventRecord, SimulationEvent};
+    use crate::model::time::Date;
+    use crate::model::town::TownId;
+    use crate::simulation::observer::ChannelObserver;
+    use crate::usecase::event::publish;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use std::sync::mpsc::channel;
+
+    fn create_character(data: &mut SimulationData, rng: &RandomNumberGenerator) {
+        if rng.roll_d100(data.date.get_year(), 0, 0) < 50 {
+            data.character_manager.create();
+        }
+    }
+
+    #[test]
+    fn default_systems() {
+        assert_eq!(
+            SimulationPipeline::default().get_names(),
+            vec![
+                "construction",
+                "marriage",
+                "birth",
+                "death",
+                "orphan_care",
+                "abandoned_buildings",
+                "building_condition"
+            ]
+        );
+    }
+
+    #[test]
+    fn add_insert_remove_and_move_systems() {
+        let mut pipeline = SimulationPipeline::empty(RandomNumberGenerator::Hash);
+
+        pipeline.add(FunctionSystem::new("a", create_character));
+        pipeline.add(FunctionSystem::new("b", create_character));
+        pipeline.insert_before("b", FunctionSystem::new("c", create_character));
+        assert_eq!(pipeline.get_names(), vec!["a", "c", "b"]);
+
+        assert!(pipeline.move_before("b", "a"));
+        assert_eq!(pipeline.get_names(), vec!["b", "a", "c"]);
+
+        assert!(pipeline.remove("a").is_some());
+        assert!(pipeline.remove("unknown").is_none());
+        assert!(!pipeline.move_before("unknown", "b"));
+        assert_eq!(pipeline.get_names(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn simulate_year_with_custom_rng() {
+        let mut data = SimulationData {
+            date: Date::new(10),
+            ..SimulationData::default()
+        };
+        let rng = RandomNumberGenerator::Mock {
+            values: HashMap::from([((10, 0, 0), 10)]),
+            default: 99,
+        };
+        let mut pipeline = SimulationPipeline::empty(rng);
+        pipeline.add(FunctionSystem::new("create", create_character));
+
+        pipeline.simulate_year(&mut data);
+        pipeline.simulate_year(&mut data);
+
+        assert_eq!(data.character_manager.get_all().len(), 1);
+        assert_eq!(data.date, Date::new(12));
+    }
+
+    fn publish_death(data: &mut SimulationData, _rng: &RandomNumberGenerator) {
+        publish(data, SimulationEvent::Death(CharacterId::new(7)));
+    }
+
+    #[test]
+    fn observers_are_notified() {
+        let mut data = SimulationData {
+            date: Date::new(10),
+            ..SimulationData::default()
+        };
+        let (sender, receiver) = channel();
+        let counter = Rc::new(RefCell::new(0));
+        let counter_clone = counter.clone();
+        let mut pipeline = SimulationPipeline::empty(RandomNumberGenerator::Hash);
+        pipeline.add(FunctionSystem::new("death", publish_death));
+        pipeline.subscribe(ChannelObserver::new(sender));
+        pipeline.subscribe(move |_event: &EventRecord| *counter_clone.borrow_mut() += 1);
+
+        pipeline.simulate_year(&mut data);
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<_>>(),
+            vec![EventRecord::new(
+                Date::new(10),
+                TownId::default(),
+                SimulationEvent::Death(CharacterId::new(7))
+            )]
+        );
+        assert_eq!(*counter.borrow(), 1);
+        assert!(data.events.is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::simulation::pipeline::SimulationPipeline;
+use crate::simulation::world::simulate_world_year;
+use crate::world::World;
+
+/// Stops the simulation of several years early.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StopCondition {
+    PopulationBelow(usize),
+    PopulationAbove(usize),
+}
+
+impl StopCondition {
+    pub fn is_met(&self, world: &World) -> bool {
+        let population = get_population(world);
+
+        match self {
+            StopCondition::PopulationBelow(value) => population < *value,
+            StopCondition::PopulationAbove(value) => population > *value,
+        }
+    }
+}
+
+/// Returns the number of residents of all towns.
+pub fn get_population(world: &World) -> usize {
+    world
+        .get_town_ids()
+        .into_iter()
+        .map(|town| world.get_residents(town).len())
+        .sum()
+}
+
+/// Returns the first condition, which is met.
+pub fn check_conditions(world: &World, conditions: &[StopCondition]) -> Option<StopCondition> {
+    conditions
+        .iter()
+        .find(|condition| condition.is_met(world))
+        .copied()
+}
+
+/// Simulates until the start of the target year, unless a condition is met before.
+/// Returns the condition, which stopped the simulation early.
+pub fn simulate_until(
+    world: &mut World,
+    pipeline: &mut SimulationPipeline,
+    target_year: u32,
+    conditions: &[StopCondition],
+) -> Option<StopCondition> {
+    while world.data().date.get_year() < target_year {
+        if let Some(condition) = check_conditions(world, conditions) {
+            return Some(condition);
+        }
+
+        simulate_world_year(world, pipeline);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::time::Date;
+    use crate::SimulationData;
+
+    fn create_world(characters: usize) -> World {
+        let mut data = SimulationData {
+            date: Date::new(10),
+            ..SimulationData::default()
+        };
+
+        for _ in 0..characters {
+            data.character_manager.create();
+        }
+
+        World::new(0, "Town", data)
+    }
+
+    #[test]
+    fn test_is_met() {
+        let world = create_world(3);
+
+        assert!(StopCondition::PopulationBelow(4).is_met(&world));
+        assert!(!StopCondition::PopulationBelow(3).is_met(&world));
+        assert!(StopCondition::PopulationAbove(2).is_met(&world));
+        assert!(!StopCondition::PopulationAbove(3).is_met(&world));
+    }
+
+    #[test]
+    fn simulate_until_target_year() {
+        let mut world = create_world(0);
+        let mut pipeline = SimulationPipeline::default();
+
+        assert_eq!(simulate_until(&mut world, &mut pipeline, 13, &[]), None);
+        assert_eq!(world.data().date, Date::new(13));
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut world = create_world(0);
+        let mut pipeline = SimulationPipeline::default();
+        let condition = StopCondition::PopulationBelow(1);
+
+        assert_eq!(
+            simulate_until(&mut world, &mut pipeline, 13, &[condition]),
+            Some(condition)
+        );
+        assert_eq!(world.data().date, Date::new(10));
+    }
+}
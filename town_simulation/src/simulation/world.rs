@@ -0,0 +1,158 @@
+use crate::generation::number::RandomNumberGenerator;
+use crate::model::character::gender::Gender::Male;
+use crate::model::character::CharacterId;
+use crate::model::town::map::LotSize;
+use crate::model::town::TownId;
+use crate::simulation::building::relocate;
+use crate::simulation::character::marriage::get_marriageable;
+use crate::simulation::pipeline::SimulationPipeline;
+use crate::usecase::character::get_gender;
+use crate::usecase::character::marriage::wed;
+use crate::usecase::character::relation::get::{get_children, get_relatives, get_spouses};
+use crate::world::{leave_town, migrate, World};
+use std::collections::HashSet;
+
+const MARRIAGE_BETWEEN_TOWNS: u32 = 7;
+
+/// Simulates all towns of the world with the pipeline, before characters marry & migrate between them.
+pub fn simulate_world_year(world: &mut World, pipeline: &mut SimulationPipeline) {
+    println!("Simulate year {}", world.data().date.get_year());
+
+    let selected = world.get_selected_town();
+
+    for town in world.get_town_ids() {
+        println!("Simulate town {}", world.get_name(town));
+        world.select(town);
+        pipeline.simulate_town(world.get_data_mut());
+    }
+
+    simulate_marriage_between_towns(world, pipeline.rng());
+    simulate_migration(world);
+    pipeline.dispatch_events(world.get_data_mut());
+
+    world.select(selected);
+    world.get_data_mut().date.increase_year();
+}
+
+/// Unmarried characters may marry someone from another town. The wife moves to the town of the husband.
+pub fn simulate_marriage_between_towns(world: &mut World, rng: &RandomNumberGenerator) {
+    let min_age = 18;
+    let max_age = 60;
+    let chance = 2;
+    let mut marriageable: Vec<(TownId, CharacterId)> = Vec::new();
+
+    for town in world.get_town_ids() {
+        world.select(town);
+        let mut ids: Vec<CharacterId> = get_marriageable(world.data(), min_age, max_age)
+            .into_iter()
+            .collect();
+        ids.sort_by_key(|id| id.id());
+        marriageable.extend(ids.into_iter().map(|id| (town, id)));
+    }
+
+    let mut married = HashSet::new();
+    let year = world.data().date.get_year();
+
+    for &(town, id) in &marriageable {
+        if married.contains(&id) {
+            continue;
+        }
+
+        let roll = rng.roll_d100(year, id.id(), MARRIAGE_BETWEEN_TOWNS);
+
+        if roll >= chance {
+            continue;
+        }
+
+        let manager = &world.data().character_manager;
+        let relatives = get_relatives(manager, id);
+        let gender = get_gender(manager, id);
+
+        if let Some(&(spouse_town, spouse)) = marriageable.iter().find(|(other_town, other)| {
+            *other_town != town
+                && !married.contains(other)
+                && !relatives.contains(other)
+                && gender.is_reverse(get_gender(manager, *other))
+        }) {
+            let ((husband, husband_town), wife) = if gender == Male {
+                ((id, town), spouse)
+            } else {
+                ((spouse, spouse_town), id)
+            };
+
+            println!(
+                "Characters {} & {} from different towns marry",
+                husband.id(),
+                wife.id()
+            );
+
+            leave_town(world, wife, husband_town);
+            world.select(husband_town);
+            wed(world.get_data_mut(), husband, wife);
+            relocate(world.get_data_mut(), vec![husband, wife]);
+
+            married.insert(husband);
+            married.insert(wife);
+        }
+    }
+}
+
+/// Households, which emigrated from a town this year, move to another town with space for them.
+pub fn simulate_migration(world: &mut World) {
+    let date = world.data().date;
+    let emigrants: Vec<CharacterId> = world
+        .data()
+        .character_manager
+        .get_all()
+        .iter()
+        .filter(|character| character.is_alive() && *character.emigration_date() == Some(date))
+        .map(|character| *character.id())
+        .collect();
+    let mut handled = HashSet::new();
+
+    for id in emigrants.iter().copied() {
+        if handled.contains(&id) {
+            continue;
+        }
+
+        let manager = &world.data().character_manager;
+        let from = *manager.get(id).unwrap().town();
+        let mut household = vec![id];
+        household.extend(
+            get_spouses(manager, id)
+                .into_iter()
+                .chain(get_children(manager, id))
+                .filter(|other| emigrants.contains(other) && !handled.contains(other))
+                .filter(|other| *manager.get(*other).unwrap().town() == from),
+        );
+        household.dedup();
+        handled.extend(household.iter().copied());
+
+        if let Some(to) = find_town_with_space(world, from) {
+            let ids: Vec<usize> = household.iter().map(|id| id.id()).collect();
+            println!(
+                "Characters {:?} migrate from {} to {}",
+                ids,
+                world.get_name(from),
+                world.get_name(to)
+            );
+            migrate(world, household, to);
+        }
+    }
+}
+
+/// Returns another town with an empty home, a free lot or space to grow.
+fn find_town_with_space(world: &World, from: TownId) -> Option<TownId> {
+    world.get_town_ids().into_iter().find(|&town| {
+        let map = world.get_map(town);
+        let has_empty_home = world
+            .get_building_manager(town)
+            .get_all()
+            .iter()
+            .filter(|building| !building.is_demolished())
+            .any(|building| building.usage().has_empty_home());
+
+        town != from
+            && (has_empty_home || map.can_grow() || !map.get_free_lots(LotSize::Single).is_empty())
+    })
+}
@@ -0,0 +1,263 @@
+use crate::history::History;
+use crate::model::character::gender::Gender;
+use crate::simulation::run::get_population;
+use crate::usecase::character::marriage::get_married_couples;
+use crate::world::World;
+use derive_getters::Getters;
+use derive_more::Constructor;
+use std::collections::BTreeMap;
+
+/// The living residents of all towns in an age group.
+#[derive(Constructor, Getters, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AgeGroup {
+    min_age: u32,
+    male: usize,
+    female: usize,
+}
+
+/// The state of the world at the start of a year & the events during it.
+#[derive(Constructor, Getters, Clone, Debug, Default, Eq, PartialEq)]
+pub struct YearStatistics {
+    year: u32,
+    population: usize,
+    births: usize,
+    deaths: usize,
+    marriages: usize,
+    /// The buildings, which are not demolished.
+    buildings: usize,
+}
+
+/// Groups the living residents of all towns by age & gender. The last group is the oldest one with residents.
+pub fn get_population_pyramid(world: &World, group_size: u32) -> Vec<AgeGroup> {
+    let data = world.data();
+    let mut groups: Vec<AgeGroup> = Vec::new();
+
+    for character in data.character_manager.get_all() {
+        if !character.is_resident() {
+            continue;
+        }
+
+        let index = (character.get_age(data.date) / group_size) as usize;
+
+        while groups.len() <= index {
+            groups.push(AgeGroup::new(groups.len() as u32 * group_size, 0, 0));
+        }
+
+        match character.gender() {
+            Gender::Male => groups[index].male += 1,
+            Gender::Female => groups[index].female += 1,
+        }
+    }
+
+    groups
+}
+
+/// Returns the average age at death or none, if nobody died yet.
+pub fn get_average_lifespan(world: &World) -> Option<f32> {
+    let data = world.data();
+    let ages: Vec<u32> = data
+        .character_manager
+        .get_all()
+        .iter()
+        .filter(|character| !character.is_alive())
+        .map(|character| character.get_age(data.date))
+        .collect();
+
+    if ages.is_empty() {
+        None
+    } else {
+        Some(ages.iter().sum::<u32>() as f32 / ages.len() as f32)
+    }
+}
+
+/// Returns the number of homes in all towns for each number of occupants.
+pub fn get_household_sizes(world: &World) -> BTreeMap<usize, usize> {
+    let mut sizes = BTreeMap::new();
+
+    for town in world.get_town_ids() {
+        for building in world.get_building_manager(town).get_all() {
+            if building.is_demolished() {
+                continue;
+            }
+
+            for home in building.usage().get_homes() {
+                if !home.is_empty() {
+                    *sizes.entry(home.occupants().len()).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+pub fn get_number_of_buildings(world: &World) -> usize {
+    world
+        .get_town_ids()
+        .into_iter()
+        .map(|town| {
+            world
+                .get_building_manager(town)
+                .get_all()
+                .iter()
+                .filter(|building| !building.is_demolished())
+                .count()
+        })
+        .sum()
+}
+
+/// Returns the statistics of each recorded year & the current one.
+/// The events of a year are taken from the snapshot of the next year,
+/// while the marriages are the difference of married couples between the snapshots.
+pub fn get_yearly_statistics(history: &History, world: &World) -> Vec<YearStatistics> {
+    let mut snapshots: Vec<&World> = history
+        .get_years()
+        .into_iter()
+        .filter(|year| *year < world.data().date.get_year())
+        .filter_map(|year| history.get(year))
+        .collect();
+    snapshots.push(world);
+
+    snapshots
+        .iter()
+        .enumerate()
+        .map(|(index, snapshot)| {
+            let next = snapshots.get(index + 1).unwrap_or(snapshot);
+            let year = snapshot.data().date.get_year();
+            let next_year = next.data().date.get_year().max(year + 1);
+            let characters = next.data().character_manager.get_all();
+            let in_year = |date: u32| date >= year && date < next_year;
+            let couples =
+                |world: &World| get_married_couples(&world.data().character_manager).len();
+
+            YearStatistics::new(
+                year,
+                get_population(snapshot),
+                characters
+                    .iter()
+                    .filter(|character| in_year(character.birth_date().get_year()))
+                    .count(),
+                characters
+                    .iter()
+                    .filter_map(|character| *character.death_date())
+                    .filter(|date| in_year(date.get_year()))
+                    .count(),
+                couples(next) - couples(snapshot),
+                get_number_of_buildings(snapshot),
+            )
+        })
+        .collect()
+}
+
+/// Exports the yearly statistics with a header as CSV.
+pub fn to_csv(statistics: &[YearStatistics]) -> String {
+    let mut csv = "year,population,births,deaths,marriages,buildings\n".to_string();
+
+    for year in statistics {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            year.year, year.population, year.births, year.deaths, year.marriages, year.buildings
+        ));
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::character::gender::Gender::{Female, Male};
+    use crate::model::character::CharacterId;
+    use crate::model::time::Date;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::usecase::character::birth::birth;
+    use crate::usecase::character::death::death;
+    use crate::usecase::character::marriage::wed;
+    use crate::usecase::character::set_gender;
+    use crate::SimulationData;
+
+    fn create_world() -> World {
+        let mut data = SimulationData {
+            date: Date::new(30),
+            ..SimulationData::default()
+        };
+        let manager = &mut data.character_manager;
+        let husband = manager.create();
+        let wife = manager.create();
+        let old = manager.create();
+        set_gender(manager, husband, Male);
+        set_gender(manager, wife, Female);
+        manager.get_mut(old).unwrap().set_birth_date(Date::new(5));
+
+        World::new(0, "Town", data)
+    }
+
+    #[test]
+    fn test_population_pyramid() {
+        let world = create_world();
+
+        assert_eq!(
+            get_population_pyramid(&world, 10),
+            vec![
+                AgeGroup::new(0, 0, 0),
+                AgeGroup::new(10, 0, 0),
+                AgeGroup::new(20, 1, 0),
+                AgeGroup::new(30, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn lifespan_and_household_sizes() {
+        let mut world = create_world();
+        let data = world.get_data_mut();
+        let [husband, wife, old] = [0, 1, 2].map(CharacterId::new);
+        let house = build(data, 0, 0, BuildingUsage::house(), husband, husband);
+        let apartments = build(data, 1, 0, BuildingUsage::apartments(3), husband, husband);
+        relocate_to_house(data, vec![husband, wife], house);
+        relocate_to_house(data, vec![old], apartments);
+
+        assert_eq!(get_average_lifespan(&world), None);
+        assert_eq!(
+            get_household_sizes(&world),
+            BTreeMap::from([(1, 1), (2, 1)])
+        );
+        assert_eq!(get_number_of_buildings(&world), 2);
+
+        death(world.get_data_mut(), old);
+        death(world.get_data_mut(), husband);
+
+        assert_eq!(get_average_lifespan(&world), Some(27.5));
+        assert_eq!(get_household_sizes(&world), BTreeMap::from([(1, 1)]));
+    }
+
+    #[test]
+    fn yearly_statistics() {
+        let mut world = create_world();
+        let mut history = History::default();
+        let [husband, wife, old] = [0, 1, 2].map(CharacterId::new);
+
+        history.record(&world);
+        let data = world.get_data_mut();
+        wed(data, husband, wife);
+        birth(data, husband, wife);
+        death(data, old);
+        data.date.increase_year();
+
+        let statistics = get_yearly_statistics(&history, &world);
+
+        assert_eq!(
+            statistics,
+            vec![
+                YearStatistics::new(30, 3, 1, 1, 1, 0),
+                YearStatistics::new(31, 3, 0, 0, 0, 0),
+            ]
+        );
+        assert_eq!(
+            to_csv(&statistics),
+            "year,population,births,deaths,marriages,buildings\n30,3,1,1,1,0\n31,3,0,0,0,0\n"
+        );
+    }
+}
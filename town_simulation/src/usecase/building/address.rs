@@ -0,0 +1,34 @@
+use crate::model::building::BuildingId;
+use crate::model::town::address::Address;
+use crate::SimulationData;
+
+/// Returns the address of a building or none, if it was demolished.
+pub fn get_address(data: &SimulationData, id: BuildingId) -> Option<Address> {
+    data.map
+        .find_building(id)
+        .map(|(block, lot)| data.map.get_address(block, lot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::condition::demolish;
+
+    #[test]
+    fn address_of_building() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let building = build(&mut data, 1, 2, BuildingUsage::house(), builder, builder);
+
+        assert_eq!(
+            get_address(&data, building),
+            Some(Address::new(5, "Street 2".to_string()))
+        );
+
+        demolish(&mut data, building);
+
+        assert_eq!(get_address(&data, building), None);
+    }
+}
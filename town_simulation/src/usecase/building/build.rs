@@ -1,13 +1,17 @@
+use crate::model::building::owner::Owner;
 use crate::model::building::usage::BuildingUsage;
 use crate::model::building::{BuildingId, BuildingMgr};
 use crate::model::character::relation::building::BuildingRelation;
 use crate::model::character::relation::building::BuildingRelationType::Builder;
 use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::event::SimulationEvent;
 use crate::usecase::building::get_building_relation;
 use crate::usecase::building::ownership::add_ownership;
+use crate::usecase::event::publish;
 use crate::SimulationData;
 use std::collections::HashSet;
 
+/// Adds a finished building to the town.
 pub fn build(
     data: &mut SimulationData,
     block: usize,
@@ -16,12 +20,86 @@ pub fn build(
     builder: CharacterId,
     owner: CharacterId,
 ) -> BuildingId {
+    let size = usage.get_lot_size();
+    let building_id = create_building(data, usage, builder, Owner::Character(owner));
+
+    data.map.add_building(building_id, block, lot, size);
+
+    building_id
+}
+
+/// Adds a finished building owned by the town, e.g. a temple.
+pub fn build_for_town(
+    data: &mut SimulationData,
+    block: usize,
+    lot: usize,
+    usage: BuildingUsage,
+    builder: CharacterId,
+) -> BuildingId {
+    let size = usage.get_lot_size();
+    let building_id = create_building(data, usage, builder, Owner::Town);
+
+    data.map.add_building(building_id, block, lot, size);
+
+    building_id
+}
+
+/// Starts a construction project, which takes several years depending on the usage.
+pub fn start_construction(
+    data: &mut SimulationData,
+    block: usize,
+    lot: usize,
+    usage: BuildingUsage,
+    builder: CharacterId,
+    owner: CharacterId,
+    workers: Vec<CharacterId>,
+) -> BuildingId {
+    let size = usage.get_lot_size();
+    let building_id = create_building(data, usage, builder, Owner::Character(owner));
+
+    data.building_manager
+        .get_mut(building_id)
+        .unwrap()
+        .start_construction(workers);
+    data.map
+        .add_construction_site(building_id, block, lot, size);
+
+    building_id
+}
+
+pub fn complete_construction(data: &mut SimulationData, building_id: BuildingId) {
+    data.building_manager
+        .get_mut(building_id)
+        .unwrap()
+        .complete_construction(data.date);
+    data.map.complete_building(building_id);
+}
+
+pub fn is_under_construction(manager: &BuildingMgr, id: BuildingId) -> bool {
+    manager.get(id).unwrap().is_under_construction()
+}
+
+pub fn get_finished_construction_sites(data: &SimulationData) -> Vec<BuildingId> {
+    data.building_manager
+        .get_all()
+        .iter()
+        .filter(|building| !building.is_demolished() && building.is_under_construction())
+        .filter(|building| building.is_construction_finished(data.date))
+        .map(|building| *building.id())
+        .collect()
+}
+
+fn create_building(
+    data: &mut SimulationData,
+    usage: BuildingUsage,
+    builder: CharacterId,
+    owner: Owner,
+) -> BuildingId {
+    let owners = owner.get_characters();
     let building_id = data
         .building_manager
         .create(usage, data.date, builder, owner);
 
-    data.map.add_building(building_id, block, lot);
-
     let builder_relation = BuildingRelation::new(Builder, building_id);
     data.character_manager
         .get_mut(builder)
@@ -29,7 +107,17 @@ pub fn build(
         .get_building_relations_mut()
         .push(builder_relation);
 
-    add_ownership(&mut data.character_manager, building_id, owner);
+    for owner in owners {
+        add_ownership(&mut data.character_manager, building_id, owner);
+    }
+
+    publish(
+        data,
+        SimulationEvent::Build {
+            building: building_id,
+            builder,
+        },
+    );
 
     building_id
 }
@@ -58,7 +146,10 @@ mod tests {
         let building = build(&mut data, 1, 2, BuildingUsage::house(), builder, owner);
 
         assert_eq!(get_builder(&data.building_manager, building), builder);
-        assert_eq!(get_owner(&data.building_manager, building), owner);
+        assert_eq!(
+            get_owner(&data.building_manager, building),
+            &Owner::Character(owner)
+        );
 
         assert(
             get_buildings_build_by(&data.character_manager, builder),
@@ -94,4 +185,38 @@ mod tests {
 
         assert_eq!(data.map.get_building(1, 2), Some(building));
     }
+
+    #[test]
+    fn construction_takes_several_years() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let worker = data.character_manager.create();
+
+        let building = start_construction(
+            &mut data,
+            1,
+            2,
+            BuildingUsage::house(),
+            builder,
+            builder,
+            vec![builder, worker],
+        );
+
+        assert!(is_under_construction(&data.building_manager, building));
+        assert_eq!(data.map.get_building(1, 2), Some(building));
+        assert_eq!(
+            data.building_manager.get(building).unwrap().workers(),
+            &vec![builder, worker]
+        );
+        assert!(get_finished_construction_sites(&data).is_empty());
+
+        data.date.increase_by(2);
+
+        assert_eq!(get_finished_construction_sites(&data), vec![building]);
+
+        complete_construction(&mut data, building);
+
+        assert!(!is_under_construction(&data.building_manager, building));
+        assert!(get_finished_construction_sites(&data).is_empty());
+    }
 }
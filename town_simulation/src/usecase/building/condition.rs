@@ -0,0 +1,95 @@
+use crate::model::building::{BuildingId, BuildingMgr};
+use crate::model::character::CharacterId;
+use crate::usecase::building::occupancy::{get_occupants, remove_occupant_from_building};
+use crate::usecase::building::ownership::revert_to_town;
+use crate::SimulationData;
+use std::collections::HashSet;
+
+pub fn get_condition(manager: &BuildingMgr, id: BuildingId) -> u32 {
+    *manager.get(id).unwrap().condition()
+}
+
+pub fn is_demolished(manager: &BuildingMgr, id: BuildingId) -> bool {
+    manager.get(id).unwrap().is_demolished()
+}
+
+pub fn decay(manager: &mut BuildingMgr, id: BuildingId, value: u32) {
+    manager.get_mut(id).unwrap().decay(value);
+}
+
+pub fn renovate(data: &mut SimulationData, id: BuildingId) {
+    data.building_manager
+        .get_mut(id)
+        .unwrap()
+        .renovate(data.date);
+}
+
+/// Removes the building from the map, but keeps it as a historical record.
+/// Returns the former occupants, who need a new home.
+pub fn demolish(data: &mut SimulationData, id: BuildingId) -> HashSet<CharacterId> {
+    let occupants = get_occupants(&data.building_manager, id);
+
+    for occupant in &occupants {
+        remove_occupant_from_building(data, *occupant);
+        data.character_manager
+            .get_mut(*occupant)
+            .unwrap()
+            .remove_occupancy();
+    }
+
+    revert_to_town(data, id);
+    data.map.remove_building(id);
+    data.building_manager
+        .get_mut(id)
+        .unwrap()
+        .set_demolition_date(data.date);
+
+    occupants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::owner::Owner;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::occupancy::get_building_occupied_by;
+    use crate::usecase::building::ownership::{get_buildings_owned_by, get_owner};
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::util::assert::assert;
+
+    #[test]
+    fn renovation_restores_condition() {
+        let mut data = SimulationData::default();
+        let owner = data.character_manager.create();
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), owner, owner);
+
+        decay(&mut data.building_manager, building, 60);
+        assert_eq!(get_condition(&data.building_manager, building), 40);
+
+        renovate(&mut data, building);
+        assert_eq!(get_condition(&data.building_manager, building), 100);
+    }
+
+    #[test]
+    fn demolition_frees_lot_and_occupants() {
+        let mut data = SimulationData::default();
+        let owner = data.character_manager.create();
+        let occupant = data.character_manager.create();
+        let building = build(&mut data, 1, 2, BuildingUsage::house(), owner, owner);
+
+        relocate_to_house(&mut data, vec![owner, occupant], building);
+
+        assert(demolish(&mut data, building), [owner, occupant]);
+
+        assert!(is_demolished(&data.building_manager, building));
+        assert!(data.map.is_lot_free(1, 2));
+        assert!(get_occupants(&data.building_manager, building).is_empty());
+        assert_eq!(
+            get_building_occupied_by(&data.character_manager, owner),
+            None
+        );
+        assert_eq!(get_owner(&data.building_manager, building), &Owner::Town);
+        assert(get_buildings_owned_by(&data.character_manager, owner), []);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::model::building::BuildingId;
+use crate::model::town::street::{Path, StreetGraph};
+use crate::SimulationData;
+
+pub fn find_path(
+    data: &SimulationData,
+    graph: &StreetGraph,
+    from: BuildingId,
+    to: BuildingId,
+) -> Option<Path> {
+    let from = data.map.find_building(from)?;
+    let to = data.map.find_building(to)?;
+
+    graph.find_path_between_lots(from, to)
+}
+
+/// Returns the walking distance in metres between 2 buildings or none, if there is no path.
+pub fn get_walking_distance(
+    data: &SimulationData,
+    graph: &StreetGraph,
+    from: BuildingId,
+    to: BuildingId,
+) -> Option<u32> {
+    find_path(data, graph, from, to).map(|path| *path.length())
+}
+
+/// Returns the walking distances from one building to several buildings with a single search.
+pub fn get_walking_distances(
+    data: &SimulationData,
+    graph: &StreetGraph,
+    from: BuildingId,
+    to: &[BuildingId],
+) -> Vec<Option<u32>> {
+    let Some(from) = data.map.find_building(from) else {
+        return vec![None; to.len()];
+    };
+    let locations: Vec<Option<(usize, usize)>> =
+        to.iter().map(|id| data.map.find_building(*id)).collect();
+    let lots: Vec<(usize, usize)> = locations.iter().flatten().copied().collect();
+    let mut distances = graph.get_walking_distances(from, &lots).into_iter();
+
+    locations
+        .into_iter()
+        .map(|location| location.and_then(|_| distances.next().flatten()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::town::street::{LOT_LENGTH, SEGMENT_LENGTH};
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::condition::demolish;
+
+    #[test]
+    fn walking_distance_between_buildings() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let building0 = build(&mut data, 0, 0, BuildingUsage::house(), builder, builder);
+        let building1 = build(&mut data, 3, 3, BuildingUsage::house(), builder, builder);
+        let graph = StreetGraph::new(&data.map);
+
+        assert_eq!(
+            get_walking_distance(&data, &graph, building0, building0),
+            Some(0)
+        );
+        assert_eq!(
+            get_walking_distance(&data, &graph, building0, building1),
+            Some(3 * SEGMENT_LENGTH + LOT_LENGTH)
+        );
+
+        demolish(&mut data, building1);
+
+        assert_eq!(
+            get_walking_distance(&data, &graph, building0, building1),
+            None
+        );
+        assert_eq!(
+            get_walking_distances(&data, &graph, building0, &[building1, building0]),
+            vec![None, Some(0)]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::model::building::owner::Owner;
+use crate::model::building::Building;
+use crate::usecase::building::address::get_address;
+use crate::SimulationData;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BuildingStatus {
+    UnderConstruction,
+    Standing,
+    Demolished,
+}
+
+impl BuildingStatus {
+    pub fn of(building: &Building) -> Self {
+        if building.is_demolished() {
+            BuildingStatus::Demolished
+        } else if building.is_under_construction() {
+            BuildingStatus::UnderConstruction
+        } else {
+            BuildingStatus::Standing
+        }
+    }
+}
+
+/// Selects buildings of the selected town. Criteria without a value match all buildings.
+#[derive(Clone, Debug, Default)]
+pub struct BuildingFilter {
+    /// Part of the usage or address, which is searched case-insensitively.
+    pub text: Option<String>,
+    /// The name of the usage, e.g. "House".
+    pub usage: Option<String>,
+    pub status: Option<BuildingStatus>,
+    /// The town or one of the owning characters.
+    pub owner: Option<Owner>,
+    pub min_age: Option<u32>,
+    pub max_age: Option<u32>,
+}
+
+impl BuildingFilter {
+    pub fn matches(&self, data: &SimulationData, building: &Building) -> bool {
+        let age = building.get_age(data.date);
+
+        self.text.as_ref().is_none_or(|text| {
+            let address = get_address(data, *building.id())
+                .map(|address| address.to_string())
+                .unwrap_or_default();
+            let text = text.to_lowercase();
+
+            building.usage().to_string().to_lowercase().contains(&text)
+                || address.to_lowercase().contains(&text)
+        }) && self
+            .usage
+            .as_ref()
+            .is_none_or(|usage| building.usage().to_string().eq_ignore_ascii_case(usage))
+            && self
+                .status
+                .is_none_or(|status| BuildingStatus::of(building) == status)
+            && self.owner.as_ref().is_none_or(|owner| match owner {
+                Owner::Town => building.owner().is_town(),
+                _ => owner
+                    .get_characters()
+                    .iter()
+                    .any(|id| building.owner().is_owned_by(*id)),
+            })
+            && self.min_age.is_none_or(|min_age| age >= min_age)
+            && self.max_age.is_none_or(|max_age| age <= max_age)
+    }
+}
+
+pub fn filter_buildings<'a>(
+    data: &'a SimulationData,
+    filter: &BuildingFilter,
+) -> Vec<&'a Building> {
+    data.building_manager
+        .get_all()
+        .iter()
+        .filter(|building| filter.matches(data, building))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::building::BuildingId;
+    use crate::model::time::Date;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::condition::demolish;
+    use crate::util::assert::assert;
+    use std::collections::HashSet;
+
+    fn filter(data: &SimulationData, filter: BuildingFilter) -> HashSet<BuildingId> {
+        filter_buildings(data, &filter)
+            .into_iter()
+            .map(|building| *building.id())
+            .collect()
+    }
+
+    #[test]
+    fn filter_buildings_by_criteria() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let house = build(&mut data, 0, 0, BuildingUsage::house(), builder, builder);
+        let apartments = build(
+            &mut data,
+            1,
+            0,
+            BuildingUsage::apartments(2),
+            builder,
+            builder,
+        );
+        data.date = Date::new(10);
+        let temple = build(&mut data, 2, 0, BuildingUsage::Temple, builder, builder);
+        demolish(&mut data, house);
+
+        let all = BuildingFilter::default();
+        let by_text = BuildingFilter {
+            text: Some("apart".to_string()),
+            ..BuildingFilter::default()
+        };
+        let by_usage = BuildingFilter {
+            usage: Some("temple".to_string()),
+            ..BuildingFilter::default()
+        };
+        let demolished = BuildingFilter {
+            status: Some(BuildingStatus::Demolished),
+            ..BuildingFilter::default()
+        };
+        let owned_by_town = BuildingFilter {
+            owner: Some(Owner::Town),
+            ..BuildingFilter::default()
+        };
+        let owned_by_builder = BuildingFilter {
+            owner: Some(Owner::Character(builder)),
+            min_age: Some(5),
+            ..BuildingFilter::default()
+        };
+
+        assert(filter(&data, all), [house, apartments, temple]);
+        assert(filter(&data, by_text), [apartments]);
+        assert(filter(&data, by_usage), [temple]);
+        assert(filter(&data, demolished), [house]);
+        assert(filter(&data, owned_by_town), [house]);
+        assert(filter(&data, owned_by_builder), [apartments]);
+    }
+}
@@ -3,7 +3,11 @@ use crate::model::character::relation::building::BuildingRelationType;
 use crate::model::character::{CharacterId, CharacterMgr};
 use std::collections::HashSet;
 
+pub mod address;
 pub mod build;
+pub mod condition;
+pub mod distance;
+pub mod filter;
 pub mod occupancy;
 pub mod ownership;
 pub mod relocate;
@@ -1,4 +1,3 @@
-use crate::model::building::usage::BuildingUsage;
 use crate::model::building::{BuildingId, BuildingMgr};
 use crate::model::character::relation::building::BuildingRelationType::Occupant;
 use crate::model::character::{CharacterId, CharacterMgr};
@@ -6,14 +5,29 @@ use crate::SimulationData;
 use std::collections::HashSet;
 
 pub fn get_occupants(manager: &BuildingMgr, id: BuildingId) -> HashSet<CharacterId> {
-    match manager.get(id).unwrap().usage() {
-        BuildingUsage::Apartments(homes) => homes
-            .iter()
-            .flat_map(|home| home.occupants().clone())
-            .into_iter()
-            .collect(),
-        BuildingUsage::House(home) => home.occupants().clone().into_iter().collect(),
+    manager
+        .get(id)
+        .unwrap()
+        .usage()
+        .get_homes()
+        .iter()
+        .flat_map(|home| home.occupants().clone())
+        .collect()
+}
+
+pub fn get_average_age_of_occupants(data: &SimulationData, id: BuildingId) -> Option<u32> {
+    let occupants = get_occupants(&data.building_manager, id);
+
+    if occupants.is_empty() {
+        return None;
     }
+
+    let total: u32 = occupants
+        .iter()
+        .map(|id| data.character_manager.get(*id).unwrap().get_age(data.date))
+        .sum();
+
+    Some(total / occupants.len() as u32)
 }
 
 pub fn get_building_occupied_by(manager: &CharacterMgr, id: CharacterId) -> Option<BuildingId> {
@@ -35,3 +49,37 @@ pub fn remove_occupant_from_building(data: &mut SimulationData, character_id: Ch
             .remove_occupant(character_id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::time::Date;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::relocate::relocate_to_house;
+
+    #[test]
+    fn test_get_average_age_of_occupants() {
+        let mut data = SimulationData {
+            date: Date::new(50),
+            ..SimulationData::default()
+        };
+        let parent = data.character_manager.create();
+        let child = data.character_manager.create();
+        data.character_manager
+            .get_mut(parent)
+            .unwrap()
+            .set_birth_date(Date::new(10));
+        data.character_manager
+            .get_mut(child)
+            .unwrap()
+            .set_birth_date(Date::new(40));
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), parent, parent);
+
+        assert_eq!(get_average_age_of_occupants(&data, building), None);
+
+        relocate_to_house(&mut data, vec![parent, child], building);
+
+        assert_eq!(get_average_age_of_occupants(&data, building), Some(25));
+    }
+}
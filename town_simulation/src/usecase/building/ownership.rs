@@ -1,43 +1,184 @@
+use crate::model::building::owner::Owner;
 use crate::model::building::{BuildingId, BuildingMgr};
 use crate::model::character::relation::building::BuildingRelation;
-use crate::model::character::relation::building::BuildingRelationType::Owner;
+use crate::model::character::relation::building::BuildingRelationType;
 use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::event::SimulationEvent;
 use crate::usecase::building::get_building_relation;
+use crate::usecase::event::publish;
 use crate::SimulationData;
 use std::collections::HashSet;
 
-pub fn get_owner(manager: &BuildingMgr, id: BuildingId) -> CharacterId {
-    *manager.get(id).unwrap().owner()
+pub fn get_owner(manager: &BuildingMgr, id: BuildingId) -> &Owner {
+    manager.get(id).unwrap().owner()
 }
 
 pub fn get_buildings_owned_by(manager: &CharacterMgr, id: CharacterId) -> HashSet<BuildingId> {
-    get_building_relation(manager, id, Owner)
+    get_building_relation(manager, id, BuildingRelationType::Owner)
 }
 
-pub fn update_owner(
-    data: &mut SimulationData,
-    building_id: BuildingId,
-    owner_id: CharacterId,
-    new_owner_id: CharacterId,
-) {
-    data.building_manager
-        .get_mut(building_id)
-        .unwrap()
-        .update_owner(new_owner_id);
+pub fn update_owner(data: &mut SimulationData, building_id: BuildingId, new_owner: Owner) {
+    let building = data.building_manager.get_mut(building_id).unwrap();
+    let previous = building.owner().clone();
+    let previous_owners = previous.get_characters();
+    let new_owners = new_owner.get_characters();
 
-    data.character_manager
-        .get_mut(owner_id)
-        .unwrap()
-        .remove_ownership(building_id);
+    building.update_owner(new_owner.clone());
 
-    add_ownership(&mut data.character_manager, building_id, new_owner_id);
+    for owner_id in &previous_owners {
+        if !new_owners.contains(owner_id) {
+            data.character_manager
+                .get_mut(*owner_id)
+                .unwrap()
+                .remove_ownership(building_id);
+        }
+    }
+
+    for owner_id in new_owners {
+        if !previous_owners.contains(&owner_id) {
+            add_ownership(&mut data.character_manager, building_id, owner_id);
+        }
+    }
+
+    if previous != new_owner {
+        publish(
+            data,
+            SimulationEvent::OwnershipChange {
+                building: building_id,
+                previous,
+                new: new_owner,
+            },
+        );
+    }
 }
 
 pub fn add_ownership(manager: &mut CharacterMgr, building_id: BuildingId, owner_id: CharacterId) {
-    let owner_relation = BuildingRelation::new(Owner, building_id);
+    let owner_relation = BuildingRelation::new(BuildingRelationType::Owner, building_id);
     manager
         .get_mut(owner_id)
         .unwrap()
         .get_building_relations_mut()
         .push(owner_relation);
 }
+
+pub fn get_buildings_owned_by_town(manager: &BuildingMgr) -> Vec<BuildingId> {
+    manager
+        .get_all()
+        .iter()
+        .filter(|building| !building.is_demolished() && building.owner().is_town())
+        .map(|building| *building.id())
+        .collect()
+}
+
+/// Returns the private buildings owned by the town or only by characters, who died or left the town.
+pub fn get_abandoned_buildings(data: &SimulationData) -> Vec<BuildingId> {
+    data.building_manager
+        .get_all()
+        .iter()
+        .filter(|building| !building.is_demolished() && !building.usage().is_public())
+        .filter(|building| {
+            building.owner().get_characters().iter().all(|owner| {
+                !data
+                    .character_manager
+                    .get(*owner)
+                    .unwrap()
+                    .lives_in(data.town)
+            })
+        })
+        .map(|building| *building.id())
+        .collect()
+}
+
+pub fn revert_to_town(data: &mut SimulationData, building_id: BuildingId) {
+    if !get_owner(&data.building_manager, building_id).is_town() {
+        update_owner(data, building_id, Owner::Town);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::usecase::building::build::{build, build_for_town};
+    use crate::util::assert::assert;
+
+    #[test]
+    fn buildings_of_dead_owners_are_abandoned() {
+        let mut data = SimulationData::default();
+        let owner0 = data.character_manager.create();
+        let owner1 = data.character_manager.create();
+
+        let building0 = build(&mut data, 0, 0, BuildingUsage::house(), owner0, owner0);
+        let building1 = build(&mut data, 0, 1, BuildingUsage::house(), owner1, owner1);
+
+        assert!(get_abandoned_buildings(&data).is_empty());
+
+        data.character_manager
+            .get_mut(owner0)
+            .unwrap()
+            .set_death_date(data.date);
+
+        assert_eq!(get_abandoned_buildings(&data), vec![building0]);
+        assert!(get_buildings_owned_by_town(&data.building_manager).is_empty());
+
+        revert_to_town(&mut data, building0);
+
+        assert_eq!(get_owner(&data.building_manager, building0), &Owner::Town);
+        assert_eq!(
+            get_buildings_owned_by_town(&data.building_manager),
+            vec![building0]
+        );
+        assert(get_buildings_owned_by(&data.character_manager, owner0), []);
+        assert(
+            get_buildings_owned_by(&data.character_manager, owner1),
+            [building1],
+        );
+    }
+
+    #[test]
+    fn buildings_of_emigrated_owners_are_abandoned() {
+        let mut data = SimulationData::default();
+        let owner = data.character_manager.create();
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), owner, owner);
+
+        data.character_manager
+            .get_mut(owner)
+            .unwrap()
+            .set_emigration_date(data.date);
+
+        assert_eq!(get_abandoned_buildings(&data), vec![building]);
+    }
+
+    #[test]
+    fn public_buildings_are_not_abandoned() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+
+        let temple = build_for_town(&mut data, 0, 0, BuildingUsage::Temple, builder);
+
+        assert_eq!(get_owner(&data.building_manager, temple), &Owner::Town);
+        assert(get_buildings_owned_by(&data.character_manager, builder), []);
+        assert!(get_abandoned_buildings(&data).is_empty());
+    }
+
+    #[test]
+    fn town_sells_building() {
+        let mut data = SimulationData::default();
+        let owner = data.character_manager.create();
+        let buyer = data.character_manager.create();
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), owner, owner);
+
+        revert_to_town(&mut data, building);
+        update_owner(&mut data, building, Owner::Character(buyer));
+
+        assert_eq!(
+            get_owner(&data.building_manager, building),
+            &Owner::Character(buyer)
+        );
+        assert!(get_buildings_owned_by_town(&data.building_manager).is_empty());
+        assert(
+            get_buildings_owned_by(&data.character_manager, buyer),
+            [building],
+        );
+    }
+}
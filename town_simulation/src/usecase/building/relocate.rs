@@ -1,9 +1,10 @@
-use crate::model::building::usage::BuildingUsage::House;
 use crate::model::building::BuildingId;
 use crate::model::character::CharacterId;
+use crate::model::event::SimulationEvent;
 use crate::usecase::building::occupancy::{
     get_building_occupied_by, remove_occupant_from_building,
 };
+use crate::usecase::event::publish;
 use crate::SimulationData;
 
 pub fn relocate_to_house(
@@ -11,29 +12,36 @@ pub fn relocate_to_house(
     character_ids: Vec<CharacterId>,
     building_id: BuildingId,
 ) {
-    if let House(home) = data
+    for character_id in &character_ids {
+        remove_occupant_from_building(data, *character_id);
+    }
+
+    if let Some(home) = data
         .building_manager
         .get_mut(building_id)
         .unwrap()
         .get_usage_mut()
+        .get_empty_home_mut()
     {
-        if home.is_empty() {
-            home.get_occupants_mut().extend(&character_ids);
-        } else {
-            panic!("House {} is not empty!", building_id.id());
-        }
+        home.get_occupants_mut().extend(&character_ids);
     } else {
-        panic!("Building {} is not a house!", building_id.id());
+        panic!("Building {} has no empty home!", building_id.id());
     }
 
-    for character_id in character_ids {
-        remove_occupant_from_building(data, character_id);
-
+    for character_id in &character_ids {
         data.character_manager
-            .get_mut(character_id)
+            .get_mut(*character_id)
             .unwrap()
             .relocate(building_id);
     }
+
+    publish(
+        data,
+        SimulationEvent::Relocation {
+            characters: character_ids,
+            building: building_id,
+        },
+    );
 }
 
 pub fn join_parents_home(
@@ -41,25 +49,42 @@ pub fn join_parents_home(
     character_ids: Vec<CharacterId>,
     parent_id: CharacterId,
 ) {
-    let building_id = get_building_occupied_by(&data.character_manager, parent_id).unwrap();
+    join_home(data, character_ids, parent_id);
+}
+
+pub fn join_home(data: &mut SimulationData, character_ids: Vec<CharacterId>, host_id: CharacterId) {
+    let building_id = get_building_occupied_by(&data.character_manager, host_id).unwrap();
+
+    for character_id in &character_ids {
+        remove_occupant_from_building(data, *character_id);
+    }
+
     let building = data.building_manager.get_mut(building_id).unwrap();
 
-    if let House(home) = building.get_usage_mut() {
+    if let Some(home) = building.get_usage_mut().get_home_of_mut(host_id) {
         home.get_occupants_mut().extend(&character_ids);
     }
 
-    for character_id in character_ids {
+    for character_id in &character_ids {
         data.character_manager
-            .get_mut(character_id)
+            .get_mut(*character_id)
             .unwrap()
             .relocate(building_id);
     }
+
+    publish(
+        data,
+        SimulationEvent::Relocation {
+            characters: character_ids,
+            building: building_id,
+        },
+    );
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::building::usage::BuildingUsage;
+    use crate::model::building::usage::{BuildingUsage, Home};
     use crate::usecase::building::build::build;
     use crate::usecase::building::occupancy::{get_building_occupied_by, get_occupants};
     use crate::util::assert::assert;
@@ -91,6 +116,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn join_home_of_another_character() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let host = data.character_manager.create();
+        let guest = data.character_manager.create();
+
+        let building0 = build(&mut data, 1, 2, BuildingUsage::house(), builder, builder);
+        let building1 = build(&mut data, 1, 3, BuildingUsage::house(), builder, builder);
+
+        relocate_to_house(&mut data, vec![guest], building0);
+        relocate_to_house(&mut data, vec![host], building1);
+        join_home(&mut data, vec![guest], host);
+
+        assert(get_occupants(&data.building_manager, building0), []);
+        assert(
+            get_occupants(&data.building_manager, building1),
+            [host, guest],
+        );
+
+        assert_eq!(
+            get_building_occupied_by(&data.character_manager, guest),
+            Some(building1)
+        );
+    }
+
     #[test]
     fn relocate_to_another_house() {
         let mut data = SimulationData::default();
@@ -112,4 +163,43 @@ mod tests {
             Some(building1)
         );
     }
+
+    #[test]
+    fn relocate_to_apartments() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let occupant0 = data.character_manager.create();
+        let occupant1 = data.character_manager.create();
+        let guest = data.character_manager.create();
+
+        let usage = BuildingUsage::apartments(2);
+        let building = build(&mut data, 1, 2, usage, builder, builder);
+
+        relocate_to_house(&mut data, vec![occupant0], building);
+        relocate_to_house(&mut data, vec![occupant1], building);
+        join_home(&mut data, vec![guest], occupant1);
+
+        assert_eq!(
+            data.building_manager.get(building).unwrap().usage(),
+            &BuildingUsage::Apartments(vec![
+                Home::new(vec![occupant0]),
+                Home::new(vec![occupant1, guest])
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn relocate_to_full_apartments() {
+        let mut data = SimulationData::default();
+        let builder = data.character_manager.create();
+        let occupant0 = data.character_manager.create();
+        let occupant1 = data.character_manager.create();
+
+        let usage = BuildingUsage::apartments(1);
+        let building = build(&mut data, 1, 2, usage, builder, builder);
+
+        relocate_to_house(&mut data, vec![occupant0], building);
+        relocate_to_house(&mut data, vec![occupant1], building);
+    }
 }
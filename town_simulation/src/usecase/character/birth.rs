@@ -2,12 +2,16 @@ use crate::model::character::relation::character::family::RelativeType;
 use crate::model::character::relation::character::family::RelativeType::*;
 use crate::model::character::relation::character::CharacterRelationType::*;
 use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::event::SimulationEvent;
 use crate::model::time::Date;
+use crate::usecase::building::occupancy::get_building_occupied_by;
 use crate::usecase::building::relocate::join_parents_home;
+use crate::usecase::character::death::is_alive;
 use crate::usecase::character::relation::get::*;
 use crate::usecase::character::{
     add_relation, add_relations, set_gender_based_on_id, set_generated_name,
 };
+use crate::usecase::event::publish;
 use crate::SimulationData;
 use std::collections::HashSet;
 
@@ -23,13 +27,30 @@ pub fn birth(data: &mut SimulationData, id0: CharacterId, id1: CharacterId) -> C
 
     set_birth_date(&mut data.character_manager, child_id, data.date);
     set_gender_based_on_id(&mut data.character_manager, child_id);
+    data.character_manager
+        .get_mut(child_id)
+        .unwrap()
+        .set_town(data.town);
     set_generated_name(
         &mut data.character_manager,
         &data.character_name_generator,
         child_id,
     );
 
-    join_parents_home(data, vec![child_id], id0);
+    publish(
+        data,
+        SimulationEvent::Birth {
+            child: child_id,
+            parents: (id0, id1),
+        },
+    );
+
+    if let Some(parent_id) = [id0, id1]
+        .into_iter()
+        .find(|id| get_building_occupied_by(&data.character_manager, *id).is_some())
+    {
+        join_parents_home(data, vec![child_id], parent_id);
+    }
 
     child_id
 }
@@ -42,6 +63,19 @@ pub fn birth_with_relations(
     let parents = [father, mother].into();
     let child = manager.create();
     let siblings = get_shared_children(manager, father, mother);
+    let half_siblings: HashSet<CharacterId> = combine(&parents, |id| get_children(manager, id))
+        .difference(&siblings)
+        .copied()
+        .collect();
+    let step_siblings: HashSet<CharacterId> =
+        combine(&parents, |id| get_step_children(manager, id))
+            .into_iter()
+            .filter(|id| !siblings.contains(id) && !half_siblings.contains(id))
+            .collect();
+    let step_parents: HashSet<CharacterId> = combine(&parents, |id| get_spouses(manager, id))
+        .into_iter()
+        .filter(|id| !parents.contains(id) && is_alive(manager, *id))
+        .collect();
     let grandparents = combine(&parents, |id| get_parents(manager, id));
     let piblings = combine(&parents, |id| get_siblings(manager, id));
     let cousins = combine(&piblings, |id| get_children(manager, id));
@@ -50,11 +84,17 @@ pub fn birth_with_relations(
     add_relations(manager, child, &cousins, Relative(Cousin));
     add_relations(manager, child, &piblings, Relative(Nibling));
     add_relations(manager, child, &siblings, Relative(Sibling));
+    add_relations(manager, child, &half_siblings, Relative(HalfSibling));
+    add_relations(manager, child, &step_siblings, Relative(StepSibling));
     add_relations(manager, child, &parents, Relative(Child));
+    add_relations(manager, child, &step_parents, Relative(StepChild));
 
     let mut relatives = HashSet::new();
     relatives.extend(parents.clone());
     relatives.extend(siblings.clone());
+    relatives.extend(half_siblings.clone());
+    relatives.extend(step_siblings.clone());
+    relatives.extend(step_parents.clone());
     relatives.extend(grandparents.clone());
     relatives.extend(piblings.clone());
     relatives.extend(cousins.clone());
@@ -63,6 +103,8 @@ pub fn birth_with_relations(
     add_in_laws(manager, child, &cousins, &relatives, Cousin);
     add_in_laws(manager, child, &piblings, &relatives, Nibling);
     add_in_laws(manager, child, &siblings, &relatives, Sibling);
+    add_in_laws(manager, child, &half_siblings, &relatives, HalfSibling);
+    add_in_laws(manager, child, &step_siblings, &relatives, StepSibling);
     add_in_laws(manager, child, &parents, &relatives, Child);
 
     child
@@ -115,6 +157,64 @@ mod tests {
         assert_eq!(get_birth_date(&manager, id), &date)
     }
 
+    #[test]
+    fn children_of_different_mothers_are_half_siblings() {
+        let mut manager = CharacterMgr::default();
+
+        let father = manager.create();
+        let mother0 = manager.create();
+        let mother1 = manager.create();
+
+        marry(&mut manager, father, mother0);
+        let child0 = birth_with_relations(&mut manager, father, mother0);
+        manager
+            .get_mut(mother0)
+            .unwrap()
+            .set_death_date(Date::new(1));
+
+        marry(&mut manager, father, mother1);
+        let child1 = birth_with_relations(&mut manager, father, mother1);
+        let child2 = birth_with_relations(&mut manager, father, mother1);
+
+        assert(get_half_siblings(&manager, child0), [child1, child2]);
+        assert(get_half_siblings(&manager, child1), [child0]);
+        assert(get_siblings(&manager, child1), [child2]);
+        assert(get_step_parents(&manager, child0), [mother1]);
+        assert(get_step_parents(&manager, child1), []);
+    }
+
+    #[test]
+    fn children_of_step_parents_are_step_siblings() {
+        let mut manager = CharacterMgr::default();
+
+        let father = manager.create();
+        let mother = manager.create();
+        let other_father = manager.create();
+
+        marry(&mut manager, other_father, mother);
+        let child0 = birth_with_relations(&mut manager, other_father, mother);
+        manager
+            .get_mut(other_father)
+            .unwrap()
+            .set_death_date(Date::new(1));
+
+        let other_mother = manager.create();
+        marry(&mut manager, father, other_mother);
+        let child1 = birth_with_relations(&mut manager, father, other_mother);
+        manager
+            .get_mut(other_mother)
+            .unwrap()
+            .set_death_date(Date::new(1));
+
+        marry(&mut manager, father, mother);
+        let child2 = birth_with_relations(&mut manager, father, mother);
+
+        assert(get_step_siblings(&manager, child0), [child1]);
+        assert(get_step_siblings(&manager, child1), [child0]);
+        assert(get_half_siblings(&manager, child2), [child0, child1]);
+        assert(get_step_siblings(&manager, child2), []);
+    }
+
     #[test]
     fn child_live_in_their_parents_home() {
         let mut data = SimulationData::default();
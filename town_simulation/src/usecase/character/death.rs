@@ -1,8 +1,9 @@
 use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::event::SimulationEvent;
 use crate::model::time::Date;
 use crate::usecase::building::occupancy::remove_occupant_from_building;
-use crate::usecase::building::ownership::{get_buildings_owned_by, update_owner};
-use crate::usecase::character::relation::get::{get_children, get_grandchildren, get_spouses};
+use crate::usecase::character::inheritance::inherit;
+use crate::usecase::event::publish;
 use crate::SimulationData;
 
 pub fn death(data: &mut SimulationData, id: CharacterId) {
@@ -13,6 +14,7 @@ pub fn death(data: &mut SimulationData, id: CharacterId) {
     character.set_death_date(data.date);
     character.remove_occupancy();
 
+    publish(data, SimulationEvent::Death(id));
     inherit(data, id);
 }
 
@@ -28,43 +30,14 @@ pub fn get_death_date(manager: &CharacterMgr, id: CharacterId) -> &Option<Date>
     manager.get(id).unwrap().death_date()
 }
 
-fn inherit(data: &mut SimulationData, id: CharacterId) {
-    if let Some(heir_id) = get_heir(&data.character_manager, id) {
-        for building_id in get_buildings_owned_by(&data.character_manager, id) {
-            update_owner(data, building_id, id, heir_id);
-        }
-    }
-}
-
-fn get_heir(manager: &CharacterMgr, id: CharacterId) -> Option<CharacterId> {
-    for spouse_id in get_spouses(manager, id) {
-        if is_alive(manager, spouse_id) {
-            return Some(spouse_id);
-        }
-    }
-
-    for child_id in get_children(manager, id) {
-        if is_alive(manager, child_id) {
-            return Some(child_id);
-        }
-    }
-
-    for grandchild_id in get_grandchildren(manager, id) {
-        if is_alive(manager, grandchild_id) {
-            return Some(grandchild_id);
-        }
-    }
-
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::building::owner::Owner;
     use crate::model::building::usage::BuildingUsage;
     use crate::usecase::building::build::{build, get_builder};
     use crate::usecase::building::occupancy::{get_building_occupied_by, get_occupants};
-    use crate::usecase::building::ownership::get_owner;
+    use crate::usecase::building::ownership::{get_buildings_owned_by, get_owner};
     use crate::usecase::building::relocate::relocate_to_house;
     use crate::usecase::character::marriage::marry;
     use crate::util::assert::assert;
@@ -120,9 +93,12 @@ mod tests {
 
         let character_id = data.character_manager.create();
         let other_id = data.character_manager.create();
-        let building_id =
-            data.building_manager
-                .create(BuildingUsage::house(), data.date, other_id, other_id);
+        let building_id = data.building_manager.create(
+            BuildingUsage::house(),
+            data.date,
+            other_id,
+            Owner::Character(other_id),
+        );
 
         relocate_to_house(&mut data, vec![character_id, other_id], building_id);
 
@@ -168,7 +144,10 @@ mod tests {
             get_builder(&data.building_manager, building_id),
             character_id
         );
-        assert_eq!(get_owner(&data.building_manager, building_id), spouse_id);
+        assert_eq!(
+            get_owner(&data.building_manager, building_id),
+            &Owner::Character(spouse_id)
+        );
         assert(
             get_buildings_owned_by(&data.character_manager, character_id),
             [],
@@ -0,0 +1,65 @@
+use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::time::Date;
+use crate::usecase::building::occupancy::remove_occupant_from_building;
+use crate::SimulationData;
+
+/// The characters leave the town & their buildings are abandoned, which reverts them to the town.
+pub fn emigrate(data: &mut SimulationData, ids: Vec<CharacterId>) {
+    for id in ids {
+        remove_occupant_from_building(data, id);
+
+        let character = data.character_manager.get_mut(id).unwrap();
+
+        character.set_emigration_date(data.date);
+        character.remove_occupancy();
+    }
+}
+
+pub fn has_emigrated(manager: &CharacterMgr, id: CharacterId) -> bool {
+    manager.get(id).unwrap().has_emigrated()
+}
+
+pub fn get_emigration_date(manager: &CharacterMgr, id: CharacterId) -> &Option<Date> {
+    manager.get(id).unwrap().emigration_date()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::occupancy::{get_building_occupied_by, get_occupants};
+    use crate::usecase::building::ownership::get_buildings_owned_by;
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::util::assert::assert;
+
+    #[test]
+    fn emigrants_leave_their_home() {
+        let mut data = SimulationData {
+            date: Date::new(42),
+            ..SimulationData::default()
+        };
+        let owner = data.character_manager.create();
+        let occupant = data.character_manager.create();
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), owner, owner);
+        relocate_to_house(&mut data, vec![owner, occupant], building);
+
+        emigrate(&mut data, vec![owner]);
+
+        assert!(has_emigrated(&data.character_manager, owner));
+        assert!(!has_emigrated(&data.character_manager, occupant));
+        assert_eq!(
+            get_emigration_date(&data.character_manager, owner),
+            &Some(Date::new(42))
+        );
+        assert_eq!(
+            get_building_occupied_by(&data.character_manager, owner),
+            None
+        );
+        assert(get_occupants(&data.building_manager, building), [occupant]);
+        assert(
+            get_buildings_owned_by(&data.character_manager, owner),
+            [building],
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::model::building::BuildingId;
+use crate::model::character::gender::Gender;
+use crate::model::character::Character;
+use crate::usecase::building::occupancy::get_building_occupied_by;
+use crate::usecase::character::marriage::is_married;
+use crate::SimulationData;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CharacterStatus {
+    Alive,
+    Emigrated,
+    Dead,
+}
+
+impl CharacterStatus {
+    pub fn of(character: &Character) -> Self {
+        if !character.is_alive() {
+            CharacterStatus::Dead
+        } else if character.has_emigrated() {
+            CharacterStatus::Emigrated
+        } else {
+            CharacterStatus::Alive
+        }
+    }
+}
+
+/// Selects characters. Criteria without a value match all characters.
+#[derive(Clone, Debug, Default)]
+pub struct CharacterFilter {
+    /// Part of the name, which is searched case-insensitively.
+    pub name: Option<String>,
+    pub status: Option<CharacterStatus>,
+    pub gender: Option<Gender>,
+    pub min_age: Option<u32>,
+    pub max_age: Option<u32>,
+    /// The current last name.
+    pub family: Option<String>,
+    /// A building of the selected town, which is occupied by the character.
+    pub building: Option<BuildingId>,
+    pub married: Option<bool>,
+}
+
+impl CharacterFilter {
+    pub fn matches(&self, data: &SimulationData, character: &Character) -> bool {
+        let manager = &data.character_manager;
+        let id = *character.id();
+        let age = character.get_age(data.date);
+
+        self.name.as_ref().is_none_or(|name| {
+            character
+                .name()
+                .to_string()
+                .to_lowercase()
+                .contains(&name.to_lowercase())
+        }) && self
+            .status
+            .is_none_or(|status| CharacterStatus::of(character) == status)
+            && self
+                .gender
+                .is_none_or(|gender| *character.gender() == gender)
+            && self.min_age.is_none_or(|min_age| age >= min_age)
+            && self.max_age.is_none_or(|max_age| age <= max_age)
+            && self.family.as_ref().is_none_or(|family| {
+                character
+                    .name()
+                    .get_last()
+                    .is_some_and(|last| last.eq_ignore_ascii_case(family))
+            })
+            && self.building.is_none_or(|building| {
+                *character.town() == data.town
+                    && get_building_occupied_by(manager, id) == Some(building)
+            })
+            && self
+                .married
+                .is_none_or(|married| is_married(manager, id) == married)
+    }
+}
+
+/// Returns the matching characters of all towns.
+pub fn filter_characters<'a>(
+    data: &'a SimulationData,
+    filter: &CharacterFilter,
+) -> Vec<&'a Character> {
+    data.character_manager
+        .get_all()
+        .iter()
+        .filter(|character| filter.matches(data, character))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::character::gender::Gender::{Female, Male};
+    use crate::model::character::name::CharacterName;
+    use crate::model::character::CharacterId;
+    use crate::model::time::Date;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::usecase::character::death::death;
+    use crate::usecase::character::marriage::marry;
+    use crate::usecase::character::{set_gender, set_name};
+    use crate::util::assert::assert;
+    use std::collections::HashSet;
+
+    fn filter(data: &SimulationData, filter: CharacterFilter) -> HashSet<CharacterId> {
+        filter_characters(data, &filter)
+            .into_iter()
+            .map(|character| *character.id())
+            .collect()
+    }
+
+    fn init() -> (SimulationData, [CharacterId; 3]) {
+        let mut data = SimulationData {
+            date: Date::new(40),
+            ..SimulationData::default()
+        };
+        let manager = &mut data.character_manager;
+        let husband = manager.create();
+        let wife = manager.create();
+        let child = manager.create();
+
+        set_name(manager, husband, CharacterName::standard("John", "Smith"));
+        set_name(
+            manager,
+            wife,
+            CharacterName::married("Mary", "Smith", "Miller"),
+        );
+        set_name(manager, child, CharacterName::standard("Anna", "Smith"));
+        set_gender(manager, husband, Male);
+        set_gender(manager, wife, Female);
+        set_gender(manager, child, Female);
+        manager
+            .get_mut(child)
+            .unwrap()
+            .set_birth_date(Date::new(30));
+        marry(manager, husband, wife);
+
+        (data, [husband, wife, child])
+    }
+
+    #[test]
+    fn empty_filter_matches_all() {
+        let (data, [husband, wife, child]) = init();
+
+        assert(
+            filter(&data, CharacterFilter::default()),
+            [husband, wife, child],
+        );
+    }
+
+    #[test]
+    fn filter_by_name_gender_age_family_and_marriage() {
+        let (data, [_, wife, child]) = init();
+
+        let by_name = CharacterFilter {
+            name: Some("miLLer".to_string()),
+            ..CharacterFilter::default()
+        };
+        let by_gender_and_age = CharacterFilter {
+            gender: Some(Female),
+            min_age: Some(20),
+            ..CharacterFilter::default()
+        };
+        let by_family = CharacterFilter {
+            family: Some("smith".to_string()),
+            max_age: Some(10),
+            ..CharacterFilter::default()
+        };
+        let unmarried = CharacterFilter {
+            married: Some(false),
+            ..CharacterFilter::default()
+        };
+
+        assert(filter(&data, by_name), [wife]);
+        assert(filter(&data, by_gender_and_age), [wife]);
+        assert(filter(&data, by_family), [child]);
+        assert(filter(&data, unmarried), [child]);
+    }
+
+    #[test]
+    fn filter_by_status_and_building() {
+        let (mut data, [husband, wife, child]) = init();
+        let house = build(&mut data, 0, 0, BuildingUsage::house(), husband, husband);
+        relocate_to_house(&mut data, vec![husband, child], house);
+        death(&mut data, wife);
+
+        let dead = CharacterFilter {
+            status: Some(CharacterStatus::Dead),
+            ..CharacterFilter::default()
+        };
+        let occupants = CharacterFilter {
+            building: Some(house),
+            ..CharacterFilter::default()
+        };
+
+        assert(filter(&data, dead), [wife]);
+        assert(filter(&data, occupants), [husband, child]);
+    }
+}
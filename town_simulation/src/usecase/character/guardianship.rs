@@ -0,0 +1,247 @@
+use crate::model::character::relation::character::family::RelativeType;
+use crate::model::character::relation::character::family::RelativeType::*;
+use crate::model::character::relation::character::CharacterRelationType::{Guardian, Relative};
+use crate::model::character::{CharacterId, CharacterMgr};
+use crate::usecase::building::occupancy::get_building_occupied_by;
+use crate::usecase::building::relocate::join_home;
+use crate::usecase::character::death::{is_alive, is_dead};
+use crate::usecase::character::relation::get::{
+    get_adoptive_parents, get_guardians, get_parents, get_relative, get_spouses,
+};
+use crate::usecase::character::{add_relation, set_name};
+use crate::SimulationData;
+
+/// The order in which relatives are asked to take care of an orphan.
+const KINSHIP_ORDER: [RelativeType; 7] = [
+    StepParent,
+    GrandParent,
+    Sibling,
+    HalfSibling,
+    Pibling,
+    StepSibling,
+    Cousin,
+];
+
+pub fn is_orphan(data: &SimulationData, id: CharacterId, max_age: u32) -> bool {
+    let manager = &data.character_manager;
+    let character = manager.get(id).unwrap();
+
+    if !character.lives_in(data.town) || character.get_age(data.date) > max_age {
+        return false;
+    }
+
+    let parents = get_parents(manager, id);
+
+    !parents.is_empty() && parents.iter().all(|parent| is_dead(manager, *parent))
+}
+
+pub fn has_living_guardian(manager: &CharacterMgr, id: CharacterId) -> bool {
+    get_guardians(manager, id)
+        .union(&get_adoptive_parents(manager, id))
+        .any(|guardian| is_alive(manager, *guardian))
+}
+
+/// Finds the closest living adult relative with a home. Ties are broken by age.
+pub fn get_guardian(data: &SimulationData, id: CharacterId, min_age: u32) -> Option<CharacterId> {
+    let manager = &data.character_manager;
+
+    for relative_type in KINSHIP_ORDER {
+        let mut candidates: Vec<CharacterId> = get_relative(manager, id, relative_type)
+            .into_iter()
+            .filter(|candidate| is_possible_guardian(data, *candidate, min_age))
+            .collect();
+
+        candidates.sort_by_key(|candidate| {
+            let character = manager.get(*candidate).unwrap();
+            (character.birth_date().get_year(), candidate.id())
+        });
+
+        if let Some(guardian) = candidates.first() {
+            return Some(*guardian);
+        }
+    }
+
+    None
+}
+
+fn is_possible_guardian(data: &SimulationData, id: CharacterId, min_age: u32) -> bool {
+    let character = data.character_manager.get(id).unwrap();
+
+    character.lives_in(data.town)
+        && character.get_age(data.date) >= min_age
+        && get_building_occupied_by(&data.character_manager, id).is_some()
+}
+
+pub fn take_in(data: &mut SimulationData, guardian: CharacterId, ward: CharacterId) {
+    println!(
+        "Character {} becomes the guardian of {}",
+        guardian.id(),
+        ward.id()
+    );
+
+    add_relation(&mut data.character_manager, guardian, ward, Guardian);
+    join_home(data, vec![ward], guardian);
+}
+
+/// The living spouse of the adoptive parent also adopts the child,
+/// who takes the family name of the adoptive parent.
+pub fn adopt(manager: &mut CharacterMgr, parent: CharacterId, child: CharacterId) {
+    let mut parents = vec![parent];
+    parents.extend(
+        get_spouses(manager, parent)
+            .into_iter()
+            .filter(|spouse| is_alive(manager, *spouse)),
+    );
+
+    for parent in parents {
+        add_relation(manager, parent, child, Relative(AdoptiveParent));
+    }
+
+    if let Some(last_name) = manager.get(parent).unwrap().name().get_last() {
+        let name = manager.get(child).unwrap().name().adopt(last_name);
+        set_name(manager, child, name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::character::name::CharacterName;
+    use crate::model::time::Date;
+    use crate::model::town::TownId;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::occupancy::get_occupants;
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::usecase::character::birth::birth_with_relations;
+    use crate::usecase::character::death::death;
+    use crate::usecase::character::get_name;
+    use crate::usecase::character::marriage::marry;
+    use crate::usecase::character::relation::get::{get_adopted_children, get_wards};
+    use crate::util::assert::assert;
+
+    #[test]
+    fn child_of_dead_parents_is_orphan() {
+        let mut data = init();
+        let [_, _, father, mother, _, child] = create_family(&mut data);
+
+        assert!(!is_orphan(&data, child, 17));
+
+        death(&mut data, father);
+        assert!(!is_orphan(&data, child, 17));
+
+        death(&mut data, mother);
+        assert!(is_orphan(&data, child, 17));
+        assert!(!is_orphan(&data, child, 9));
+    }
+
+    #[test]
+    fn grandparent_is_guardian_before_aunt() {
+        let mut data = init();
+        let [grandfather, grandmother, father, mother, aunt, child] = create_family(&mut data);
+
+        death(&mut data, father);
+        death(&mut data, mother);
+
+        assert_eq!(get_guardian(&data, child, 18), Some(grandfather));
+
+        death(&mut data, grandfather);
+        death(&mut data, grandmother);
+
+        assert_eq!(get_guardian(&data, child, 18), Some(aunt));
+    }
+
+    #[test]
+    fn guardian_lives_in_same_town() {
+        let mut data = init();
+        let [grandfather, grandmother, father, mother, aunt, child] = create_family(&mut data);
+
+        death(&mut data, father);
+        death(&mut data, mother);
+
+        for grandparent in [grandfather, grandmother] {
+            data.character_manager
+                .get_mut(grandparent)
+                .unwrap()
+                .set_town(TownId::new(1));
+        }
+
+        assert_eq!(get_guardian(&data, child, 18), Some(aunt));
+    }
+
+    #[test]
+    fn guardian_takes_in_orphan() {
+        let mut data = init();
+        let [grandfather, grandmother, father, mother, _, child] = create_family(&mut data);
+
+        death(&mut data, father);
+        death(&mut data, mother);
+
+        take_in(&mut data, grandfather, child);
+
+        assert(get_guardians(&data.character_manager, child), [grandfather]);
+        assert(get_wards(&data.character_manager, grandfather), [child]);
+        assert!(has_living_guardian(&data.character_manager, child));
+
+        let home = get_building_occupied_by(&data.character_manager, grandfather).unwrap();
+        assert(
+            get_occupants(&data.building_manager, home),
+            [grandfather, grandmother, child],
+        );
+    }
+
+    #[test]
+    fn adopted_child_takes_family_name() {
+        let mut manager = CharacterMgr::default();
+        let father = manager.create();
+        let mother = manager.create();
+        let child = manager.create();
+
+        set_name(&mut manager, father, CharacterName::standard("A", "B"));
+        set_name(&mut manager, child, CharacterName::standard("C", "D"));
+        marry(&mut manager, father, mother);
+
+        adopt(&mut manager, father, child);
+
+        assert(get_adoptive_parents(&manager, child), [father, mother]);
+        assert(get_adopted_children(&manager, mother), [child]);
+        assert_eq!(
+            get_name(&manager, child),
+            &CharacterName::standard("C", "B")
+        );
+    }
+
+    fn init() -> SimulationData {
+        SimulationData {
+            date: Date::new(30),
+            ..SimulationData::default()
+        }
+    }
+
+    fn create_family(data: &mut SimulationData) -> [CharacterId; 6] {
+        let manager = &mut data.character_manager;
+        let grandfather = manager.create();
+        let grandmother = manager.create();
+        marry(manager, grandfather, grandmother);
+
+        let father = birth_with_relations(manager, grandfather, grandmother);
+        let aunt = birth_with_relations(manager, grandfather, grandmother);
+        let mother = manager.create();
+        marry(manager, father, mother);
+
+        let child = birth_with_relations(manager, father, mother);
+        manager
+            .get_mut(child)
+            .unwrap()
+            .set_birth_date(Date::new(20));
+
+        let home0 = build(data, 0, 0, BuildingUsage::house(), father, father);
+        let home1 = build(data, 0, 1, BuildingUsage::house(), aunt, aunt);
+        let home2 = build(data, 0, 2, BuildingUsage::house(), grandfather, grandfather);
+        relocate_to_house(data, vec![father, mother, child], home0);
+        relocate_to_house(data, vec![aunt], home1);
+        relocate_to_house(data, vec![grandfather, grandmother], home2);
+
+        [grandfather, grandmother, father, mother, aunt, child]
+    }
+}
@@ -0,0 +1,319 @@
+use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::inheritance::InheritanceLaw;
+use crate::model::inheritance::InheritanceLaw::*;
+use crate::model::town::TownId;
+use crate::usecase::building::ownership::{get_buildings_owned_by, get_owner, update_owner};
+use crate::usecase::character::relation::get::{
+    get_adopted_children, get_children, get_grandchildren, get_spouses,
+};
+use crate::SimulationData;
+use std::collections::HashSet;
+
+pub fn get_testament(manager: &CharacterMgr, id: CharacterId) -> Option<CharacterId> {
+    *manager.get(id).unwrap().testament()
+}
+
+pub fn set_testament(manager: &mut CharacterMgr, id: CharacterId, heir: Option<CharacterId>) {
+    manager.get_mut(id).unwrap().set_testament(heir);
+}
+
+/// Splits the shares of all buildings owned by the character among the heirs of the same town.
+/// Without heirs the other co-owners get the share or else the building falls to the town.
+pub fn inherit(data: &mut SimulationData, id: CharacterId) {
+    let heirs = get_heirs(&data.character_manager, id, data.inheritance_law, data.town);
+
+    for building_id in get_buildings_owned_by(&data.character_manager, id) {
+        let owner = get_owner(&data.building_manager, building_id).pass_share(id, &heirs);
+
+        update_owner(data, building_id, owner);
+    }
+}
+
+/// Returns the heirs of the character living in the town, which are sorted by age.
+pub fn get_heirs(
+    manager: &CharacterMgr,
+    id: CharacterId,
+    law: InheritanceLaw,
+    town: TownId,
+) -> Vec<CharacterId> {
+    let spouses = get_residents(manager, town, get_spouses(manager, id));
+    let descendants = get_descendants(manager, id, town);
+
+    match law {
+        Primogeniture => first_or(descendants.first(), spouses),
+        Ultimogeniture => first_or(descendants.last(), spouses),
+        EqualPartition => {
+            if descendants.is_empty() {
+                spouses
+            } else {
+                descendants
+            }
+        }
+        SpouseThenChildren => {
+            if spouses.is_empty() {
+                first_or(descendants.first(), Vec::new())
+            } else {
+                spouses
+            }
+        }
+        Testament => match get_testament(manager, id) {
+            Some(heir) if manager.get(heir).unwrap().lives_in(town) => vec![heir],
+            _ => get_heirs(manager, id, SpouseThenChildren, town),
+        },
+    }
+}
+
+fn first_or(heir: Option<&CharacterId>, alternative: Vec<CharacterId>) -> Vec<CharacterId> {
+    heir.map(|heir| vec![*heir]).unwrap_or(alternative)
+}
+
+/// Returns the (adopted) children living in the town or, if there are none, the grandchildren living there.
+fn get_descendants(manager: &CharacterMgr, id: CharacterId, town: TownId) -> Vec<CharacterId> {
+    let mut children = get_children(manager, id);
+    children.extend(get_adopted_children(manager, id));
+    let children = get_residents(manager, town, children);
+
+    if children.is_empty() {
+        get_residents(manager, town, get_grandchildren(manager, id))
+    } else {
+        children
+    }
+}
+
+/// Returns the residents of the town sorted from oldest to youngest.
+fn get_residents(
+    manager: &CharacterMgr,
+    town: TownId,
+    ids: HashSet<CharacterId>,
+) -> Vec<CharacterId> {
+    let mut residents: Vec<CharacterId> = ids
+        .into_iter()
+        .filter(|id| manager.get(*id).unwrap().lives_in(town))
+        .collect();
+
+    residents.sort_by_key(|id| (manager.get(*id).unwrap().birth_date().get_year(), id.id()));
+
+    residents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::owner::Owner;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::time::Date;
+    use crate::usecase::building::build::build;
+    use crate::usecase::character::birth::birth_with_relations;
+    use crate::usecase::character::death::death;
+    use crate::usecase::character::marriage::marry;
+    use crate::util::assert::assert;
+
+    #[test]
+    fn heirs_of_each_law() {
+        let mut data = SimulationData::default();
+        let [father, mother, child0, child1, child2] = create_family(&mut data);
+        let manager = &data.character_manager;
+
+        assert_eq!(
+            get_heirs(manager, father, Primogeniture, TownId::default()),
+            vec![child0]
+        );
+        assert_eq!(
+            get_heirs(manager, father, Ultimogeniture, TownId::default()),
+            vec![child2]
+        );
+        assert_eq!(
+            get_heirs(manager, father, EqualPartition, TownId::default()),
+            vec![child0, child1, child2]
+        );
+        assert_eq!(
+            get_heirs(manager, father, SpouseThenChildren, TownId::default()),
+            vec![mother]
+        );
+        assert_eq!(
+            get_heirs(manager, father, Testament, TownId::default()),
+            vec![mother]
+        );
+    }
+
+    #[test]
+    fn heir_of_testament() {
+        let mut data = SimulationData::default();
+        let [father, _, _, child1, _] = create_family(&mut data);
+
+        set_testament(&mut data.character_manager, father, Some(child1));
+
+        assert_eq!(get_testament(&data.character_manager, father), Some(child1));
+        assert_eq!(
+            get_heirs(
+                &data.character_manager,
+                father,
+                Testament,
+                TownId::default()
+            ),
+            vec![child1]
+        );
+    }
+
+    #[test]
+    fn spouse_inherits_without_descendants() {
+        let mut data = SimulationData::default();
+        let [father, mother, child0, child1, child2] = create_family(&mut data);
+
+        for child in [child0, child1, child2] {
+            death(&mut data, child);
+        }
+
+        let manager = &data.character_manager;
+
+        assert_eq!(
+            get_heirs(manager, father, Primogeniture, TownId::default()),
+            vec![mother]
+        );
+        assert_eq!(
+            get_heirs(manager, father, EqualPartition, TownId::default()),
+            vec![mother]
+        );
+    }
+
+    #[test]
+    fn children_become_co_owners() {
+        let mut data = SimulationData {
+            inheritance_law: EqualPartition,
+            ..SimulationData::default()
+        };
+        let [father, _, child0, child1, child2] = create_family(&mut data);
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), father, father);
+
+        death(&mut data, father);
+
+        assert_eq!(
+            get_owner(&data.building_manager, building),
+            &Owner::from_characters(vec![child0, child1, child2])
+        );
+        assert(get_buildings_owned_by(&data.character_manager, father), []);
+        assert(
+            get_buildings_owned_by(&data.character_manager, child1),
+            [building],
+        );
+
+        death(&mut data, child1);
+
+        assert_eq!(
+            get_owner(&data.building_manager, building),
+            &Owner::from_characters(vec![child0, child2])
+        );
+    }
+
+    #[test]
+    fn co_owner_keeps_share() {
+        let mut data = SimulationData {
+            inheritance_law: EqualPartition,
+            ..SimulationData::default()
+        };
+        let [father, _, child0, child1, child2] = create_family(&mut data);
+        let other = data.character_manager.create();
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), father, father);
+        update_owner(
+            &mut data,
+            building,
+            Owner::from_characters(vec![father, other]),
+        );
+
+        death(&mut data, father);
+
+        let owner = get_owner(&data.building_manager, building);
+
+        assert_eq!(
+            owner,
+            &Owner::CoOwners(vec![(other, 3), (child0, 1), (child1, 1), (child2, 1)])
+        );
+        assert_eq!(owner.get_share(other), (3, 6));
+        assert_eq!(owner.get_share(child1), (1, 6));
+    }
+
+    #[test]
+    fn town_inherits_without_heirs() {
+        let mut data = SimulationData::default();
+        let character = data.character_manager.create();
+        let building = build(
+            &mut data,
+            0,
+            0,
+            BuildingUsage::house(),
+            character,
+            character,
+        );
+
+        death(&mut data, character);
+
+        assert_eq!(get_owner(&data.building_manager, building), &Owner::Town);
+        assert(
+            get_buildings_owned_by(&data.character_manager, character),
+            [],
+        );
+    }
+
+    #[test]
+    fn heir_in_other_town_is_skipped() {
+        let mut data = SimulationData {
+            inheritance_law: Primogeniture,
+            ..SimulationData::default()
+        };
+        let [father, _, child0, child1, _] = create_family(&mut data);
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), father, father);
+        data.character_manager
+            .get_mut(child0)
+            .unwrap()
+            .immigrate(TownId::new(1));
+
+        death(&mut data, father);
+
+        assert_eq!(
+            get_owner(&data.building_manager, building),
+            &Owner::Character(child1)
+        );
+    }
+
+    #[test]
+    fn heirs_in_other_towns_inherit_nothing() {
+        let mut data = SimulationData {
+            inheritance_law: EqualPartition,
+            ..SimulationData::default()
+        };
+        let [father, _, child0, child1, child2] = create_family(&mut data);
+        let building = build(&mut data, 0, 0, BuildingUsage::house(), father, father);
+        data.character_manager
+            .get_mut(child1)
+            .unwrap()
+            .immigrate(TownId::new(1));
+
+        death(&mut data, father);
+
+        assert_eq!(
+            get_owner(&data.building_manager, building),
+            &Owner::from_characters(vec![child0, child2])
+        );
+        assert(get_buildings_owned_by(&data.character_manager, child1), []);
+    }
+
+    fn create_family(data: &mut SimulationData) -> [CharacterId; 5] {
+        let manager = &mut data.character_manager;
+        let father = manager.create();
+        let mother = manager.create();
+        marry(manager, father, mother);
+
+        let mut children = Vec::new();
+
+        for year in [12, 10, 14] {
+            let child = birth_with_relations(manager, father, mother);
+            manager
+                .get_mut(child)
+                .unwrap()
+                .set_birth_date(Date::new(year));
+            children.push(child);
+        }
+
+        [father, mother, children[1], children[0], children[2]]
+    }
+}
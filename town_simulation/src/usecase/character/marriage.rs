@@ -1,8 +1,17 @@
+use crate::model::character::relation::character::family::RelativeType::{
+    Child, StepParent, StepSibling,
+};
 use crate::model::character::relation::character::CharacterRelation;
-use crate::model::character::relation::character::CharacterRelationType::Spouse;
+use crate::model::character::relation::character::CharacterRelationType::{Relative, Spouse};
 use crate::model::character::{Character, CharacterId, CharacterMgr};
-use crate::usecase::character::relation::get::{get_relation_to_relatives, get_spouses};
+use crate::model::event::SimulationEvent;
+use crate::usecase::character::relation::check::is_relative;
+use crate::usecase::character::relation::get::{
+    get_children, get_relation_to_relatives, get_spouses,
+};
 use crate::usecase::character::{add_relation, add_relations};
+use crate::usecase::event::publish;
+use crate::SimulationData;
 use std::collections::HashSet;
 
 pub fn get_married_couples(manager: &CharacterMgr) -> HashSet<(CharacterId, CharacterId)> {
@@ -46,15 +55,22 @@ fn is_character_married(character: &Character) -> bool {
 pub fn marry(manager: &mut CharacterMgr, id0: CharacterId, id1: CharacterId) {
     update_in_laws(manager, id0, id1);
     update_in_laws(manager, id1, id0);
+    update_step_family(manager, id0, id1);
     add_relations(manager, id0, &vec![id1].into_iter().collect(), Spouse);
     update_names(manager, id0, id1);
 }
 
+/// Marries the characters & publishes the event.
+pub fn wed(data: &mut SimulationData, husband: CharacterId, wife: CharacterId) {
+    marry(&mut data.character_manager, husband, wife);
+    publish(data, SimulationEvent::Marriage { husband, wife });
+}
+
 fn update_in_laws(manager: &mut CharacterMgr, from: CharacterId, to: CharacterId) {
     let in_laws: Vec<CharacterRelation> = get_relation_to_relatives(manager, from)
         .iter()
-        .map(|&relation| relation.to_in_law())
-        .flatten()
+        .filter(|&relation| *relation.relation_type() != Relative(Child))
+        .filter_map(|&relation| relation.to_in_law())
         .collect();
 
     for in_law in in_laws {
@@ -62,8 +78,28 @@ fn update_in_laws(manager: &mut CharacterMgr, from: CharacterId, to: CharacterId
     }
 }
 
+fn update_step_family(manager: &mut CharacterMgr, id0: CharacterId, id1: CharacterId) {
+    let children0 = get_children(manager, id0);
+    let children1 = get_children(manager, id1);
+    let step_children0: HashSet<CharacterId> = children1.difference(&children0).copied().collect();
+    let step_children1: HashSet<CharacterId> = children0.difference(&children1).copied().collect();
+
+    add_relations(manager, id0, &step_children0, Relative(StepParent));
+    add_relations(manager, id1, &step_children1, Relative(StepParent));
+
+    for child in &step_children0 {
+        let step_siblings: HashSet<CharacterId> = step_children1
+            .iter()
+            .filter(|&other| !is_relative(manager, *child, *other))
+            .copied()
+            .collect();
+
+        add_relations(manager, *child, &step_siblings, Relative(StepSibling));
+    }
+}
+
 fn update_names(manager: &mut CharacterMgr, id0: CharacterId, id1: CharacterId) {
-    if let Some(last_name) = manager.get(id0).map(|c| c.name().get_last()).flatten() {
+    if let Some(last_name) = manager.get(id0).and_then(|c| c.name().get_last()) {
         let last_name = last_name.to_string();
 
         if let Some(character) = manager.get_mut(id1) {
@@ -78,9 +114,11 @@ mod tests {
     use super::*;
     use crate::model::character::name::CharacterName;
     use crate::model::character::relation::character::family::RelativeType;
-    use crate::model::character::relation::character::family::RelativeType::{Child, Parent};
-    use crate::model::character::relation::character::CharacterRelationType::{InLaw, Relative};
-    use crate::usecase::character::relation::get::{get_relation_to_in_laws, get_spouses};
+    use crate::model::character::relation::character::family::RelativeType::{Parent, Sibling};
+    use crate::model::character::relation::character::CharacterRelationType::InLaw;
+    use crate::usecase::character::relation::get::{
+        get_relation_to_in_laws, get_step_children, get_step_parents, get_step_siblings,
+    };
     use crate::usecase::character::{get_name, set_name};
     use crate::util::assert::assert;
 
@@ -185,6 +223,58 @@ mod tests {
         assert_in_law(&manager, wife, husband_parent, Parent);
     }
 
+    #[test]
+    fn children_of_spouse_become_step_children() {
+        let mut manager = CharacterMgr::default();
+
+        let husband = manager.create();
+        let wife = manager.create();
+
+        let husband_child = manager.create();
+        let wife_child = manager.create();
+
+        add_relation(&mut manager, husband_child, husband, Relative(Child));
+        add_relation(&mut manager, wife_child, wife, Relative(Child));
+
+        marry(&mut manager, husband, wife);
+
+        assert(get_step_children(&manager, husband), [wife_child]);
+        assert(get_step_children(&manager, wife), [husband_child]);
+        assert(get_step_parents(&manager, husband_child), [wife]);
+        assert(get_step_parents(&manager, wife_child), [husband]);
+        assert(get_step_siblings(&manager, husband_child), [wife_child]);
+        assert(get_step_siblings(&manager, wife_child), [husband_child]);
+        assert!(get_relation_to_in_laws(&manager, husband_child).is_empty());
+    }
+
+    #[test]
+    fn children_of_spouse_are_no_in_laws() {
+        let mut manager = CharacterMgr::default();
+
+        let husband = manager.create();
+        let wife = manager.create();
+
+        let husband_parent = manager.create();
+        let husband_sibling = manager.create();
+        let husband_child = manager.create();
+
+        add_relation(&mut manager, husband, husband_parent, Relative(Child));
+        add_relation(&mut manager, husband, husband_sibling, Relative(Sibling));
+        add_relation(&mut manager, husband_child, husband, Relative(Child));
+
+        marry(&mut manager, husband, wife);
+
+        assert_eq!(
+            get_relation_to_in_laws(&manager, wife),
+            vec![
+                &CharacterRelation::new(InLaw(Parent), husband_parent),
+                &CharacterRelation::new(InLaw(Sibling), husband_sibling),
+            ]
+        );
+        assert!(get_relation_to_in_laws(&manager, husband_child).is_empty());
+        assert(get_step_children(&manager, wife), [husband_child]);
+    }
+
     fn assert_in_law(
         manager: &CharacterMgr,
         character: CharacterId,
@@ -192,11 +282,11 @@ mod tests {
         in_law_type: RelativeType,
     ) {
         assert_eq!(
-            get_relation_to_in_laws(&manager, character),
+            get_relation_to_in_laws(manager, character),
             vec![&CharacterRelation::new(InLaw(in_law_type), in_law)]
         );
         assert_eq!(
-            get_relation_to_in_laws(&manager, in_law),
+            get_relation_to_in_laws(manager, in_law),
             vec![&CharacterRelation::new(
                 InLaw(in_law_type.reverse()),
                 character
@@ -7,8 +7,13 @@ use std::collections::HashSet;
 
 pub mod birth;
 pub mod death;
+pub mod emigration;
+pub mod filter;
+pub mod guardianship;
+pub mod inheritance;
 pub mod marriage;
 pub mod relation;
+pub mod wealth;
 
 pub fn get_name(manager: &CharacterMgr, id: CharacterId) -> &CharacterName {
     manager.get(id).unwrap().name()
@@ -37,7 +42,7 @@ pub fn set_gender(manager: &mut CharacterMgr, id: CharacterId, gender: Gender) {
 }
 
 pub fn set_gender_based_on_id(manager: &mut CharacterMgr, id: CharacterId) {
-    let gender = if id.id() % 2 == 0 {
+    let gender = if id.id().is_multiple_of(2) {
         Gender::Male
     } else {
         Gender::Female
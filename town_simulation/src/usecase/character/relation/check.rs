@@ -70,7 +70,7 @@ mod tests {
         to: CharacterId,
         result: Option<CharacterRelationType>,
     ) {
-        assert_eq!(get_relation(&manager, from, to), result);
-        assert_eq!(is_relative(&manager, from, to), result.is_some());
+        assert_eq!(get_relation(manager, from, to), result);
+        assert_eq!(is_relative(manager, from, to), result.is_some());
     }
 }
@@ -17,6 +17,20 @@ where
     combined_ids
 }
 
+pub fn get_adopted_children(
+    manager: &CharacterMgr,
+    character_id: CharacterId,
+) -> HashSet<CharacterId> {
+    get_relative(manager, character_id, AdoptedChild)
+}
+
+pub fn get_adoptive_parents(
+    manager: &CharacterMgr,
+    character_id: CharacterId,
+) -> HashSet<CharacterId> {
+    get_relative(manager, character_id, AdoptiveParent)
+}
+
 pub fn get_children(manager: &CharacterMgr, character_id: CharacterId) -> HashSet<CharacterId> {
     get_relative(manager, character_id, Child)
 }
@@ -40,6 +54,13 @@ pub fn get_niblings(manager: &CharacterMgr, character_id: CharacterId) -> HashSe
     get_relative(manager, character_id, Nibling)
 }
 
+pub fn get_half_siblings(
+    manager: &CharacterMgr,
+    character_id: CharacterId,
+) -> HashSet<CharacterId> {
+    get_relative(manager, character_id, HalfSibling)
+}
+
 pub fn get_grandchildren(
     manager: &CharacterMgr,
     character_id: CharacterId,
@@ -63,6 +84,24 @@ pub fn get_siblings(manager: &CharacterMgr, character_id: CharacterId) -> HashSe
     get_relative(manager, character_id, Sibling)
 }
 
+pub fn get_step_children(
+    manager: &CharacterMgr,
+    character_id: CharacterId,
+) -> HashSet<CharacterId> {
+    get_relative(manager, character_id, StepChild)
+}
+
+pub fn get_step_parents(manager: &CharacterMgr, character_id: CharacterId) -> HashSet<CharacterId> {
+    get_relative(manager, character_id, StepParent)
+}
+
+pub fn get_step_siblings(
+    manager: &CharacterMgr,
+    character_id: CharacterId,
+) -> HashSet<CharacterId> {
+    get_relative(manager, character_id, StepSibling)
+}
+
 pub fn get_relation_to_in_laws(
     manager: &CharacterMgr,
     character_id: CharacterId,
@@ -96,7 +135,7 @@ pub fn get_relatives(manager: &CharacterMgr, character_id: CharacterId) -> HashS
         .collect()
 }
 
-fn get_relative(
+pub fn get_relative(
     manager: &CharacterMgr,
     character_id: CharacterId,
     relative_type: RelativeType,
@@ -108,6 +147,27 @@ fn get_relative(
     )
 }
 
+pub fn get_guardians(manager: &CharacterMgr, character_id: CharacterId) -> HashSet<CharacterId> {
+    get_direct_relation(manager, character_id, CharacterRelationType::Guardian)
+}
+
+pub fn get_wards(manager: &CharacterMgr, character_id: CharacterId) -> HashSet<CharacterId> {
+    get_direct_relation(manager, character_id, CharacterRelationType::Ward)
+}
+
+pub fn get_relation_to_guardianship(
+    manager: &CharacterMgr,
+    character_id: CharacterId,
+) -> Vec<&CharacterRelation> {
+    manager
+        .get(character_id)
+        .unwrap()
+        .character_relations
+        .iter()
+        .filter(|&relation| relation.relation_type().is_guardianship())
+        .collect()
+}
+
 pub fn get_spouses(manager: &CharacterMgr, character_id: CharacterId) -> HashSet<CharacterId> {
     get_direct_relation(manager, character_id, CharacterRelationType::Spouse)
 }
@@ -0,0 +1,76 @@
+use crate::model::building::BuildingMgr;
+use crate::model::character::{CharacterId, CharacterMgr};
+use crate::model::town::map::TownMap;
+use crate::usecase::building::ownership::get_buildings_owned_by;
+use crate::SimulationData;
+
+/// Returns the share of the land values of all buildings owned by the character.
+pub fn get_wealth(data: &SimulationData, id: CharacterId) -> u32 {
+    get_wealth_in_town(
+        &data.character_manager,
+        &data.building_manager,
+        &data.map,
+        id,
+    )
+}
+
+/// Like [`get_wealth`], but for a town, which might not be the simulated one.
+pub fn get_wealth_in_town(
+    manager: &CharacterMgr,
+    buildings: &BuildingMgr,
+    map: &TownMap,
+    id: CharacterId,
+) -> u32 {
+    get_buildings_owned_by(manager, id)
+        .into_iter()
+        .filter_map(|building_id| {
+            let (share, total) = buildings.get(building_id).unwrap().owner().get_share(id);
+
+            map.find_building(building_id)
+                .map(|(block, _)| map.get_land_value(block) * share / total)
+        })
+        .sum()
+}
+
+pub fn get_household_wealth(data: &SimulationData, ids: &[CharacterId]) -> u32 {
+    ids.iter().map(|id| get_wealth(data, *id)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::owner::Owner;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::model::town::map::TownMap;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::condition::demolish;
+    use crate::usecase::building::ownership::update_owner;
+
+    #[test]
+    fn wealth_of_owners() {
+        let mut data = SimulationData {
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let owner0 = data.character_manager.create();
+        let owner1 = data.character_manager.create();
+        let poor = data.character_manager.create();
+        let centre = build(&mut data, 4, 0, BuildingUsage::house(), owner0, owner0);
+        let corner = build(&mut data, 8, 0, BuildingUsage::house(), owner0, owner0);
+
+        update_owner(
+            &mut data,
+            centre,
+            Owner::from_characters(vec![owner0, owner1]),
+        );
+
+        assert_eq!(get_wealth(&data, owner0), 100);
+        assert_eq!(get_wealth(&data, owner1), 50);
+        assert_eq!(get_wealth(&data, poor), 0);
+        assert_eq!(get_household_wealth(&data, &[owner0, owner1, poor]), 150);
+
+        demolish(&mut data, corner);
+
+        assert_eq!(get_wealth(&data, owner0), 50);
+    }
+}
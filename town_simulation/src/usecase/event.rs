@@ -0,0 +1,38 @@
+use crate::model::event::{EventRecord, SimulationEvent};
+use crate::SimulationData;
+
+/// Records the event, so it can be dispatched to the observers.
+pub fn publish(data: &mut SimulationData, event: SimulationEvent) {
+    data.events
+        .push(EventRecord::new(data.date, data.town, event));
+}
+
+/// Returns all events, which were published since the last call.
+pub fn take_events(data: &mut SimulationData) -> Vec<EventRecord> {
+    std::mem::take(&mut data.events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::CharacterId;
+    use crate::model::time::Date;
+    use crate::model::town::TownId;
+
+    #[test]
+    fn take_published_events() {
+        let mut data = SimulationData {
+            date: Date::new(42),
+            ..SimulationData::default()
+        };
+        let event = SimulationEvent::Death(CharacterId::new(3));
+
+        publish(&mut data, event.clone());
+
+        assert_eq!(
+            take_events(&mut data),
+            vec![EventRecord::new(Date::new(42), TownId::default(), event)]
+        );
+        assert!(take_events(&mut data).is_empty());
+    }
+}
@@ -1,2 +1,4 @@
 pub mod building;
 pub mod character;
+pub mod event;
+pub mod town;
@@ -0,0 +1,128 @@
+use crate::generation::name::street::StreetNameGenerator;
+use crate::model::building::usage::BuildingUsage;
+use crate::model::town::map::TownMap;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+pub fn set_generated_street_names(map: &mut TownMap, generator: &StreetNameGenerator) {
+    let mut used = HashSet::new();
+    let horizontal = generate_street_names(generator, map.height() + 1, 0, "Street", &mut used);
+    let vertical = generate_street_names(generator, map.width() + 1, 1, "Road", &mut used);
+
+    map.set_street_names(horizontal, vertical);
+}
+
+/// Adds a row or a column of blocks, whichever keeps the map closer to a square.
+/// Returns false, if the map already reached its maximum size.
+pub fn grow_map(map: &mut TownMap, generator: &StreetNameGenerator) -> bool {
+    let used: HashSet<String> = map
+        .horizontal_streets()
+        .iter()
+        .chain(map.vertical_streets())
+        .cloned()
+        .collect();
+
+    if map.can_add_row() && (map.height() <= map.width() || !map.can_add_column()) {
+        let name = generator.generate((map.height() + 1) * 2, "Street", &used);
+        map.add_row(name);
+    } else if map.can_add_column() {
+        let name = generator.generate((map.width() + 1) * 2 + 1, "Road", &used);
+        map.add_column(name);
+    } else {
+        return false;
+    }
+
+    true
+}
+
+/// Returns the most valuable free lot, which is zoned for the usage & affordable with the budget.
+/// If no lot is affordable, the cheapest one is returned.
+pub fn find_best_lot(map: &TownMap, usage: &BuildingUsage, budget: u32) -> Option<(usize, usize)> {
+    let lots: Vec<(usize, usize)> = map
+        .get_free_lots(usage.get_lot_size())
+        .into_iter()
+        .filter(|(block, _)| map.get_district(*block).is_allowed(usage))
+        .collect();
+
+    lots.iter()
+        .filter(|(block, _)| map.get_land_value(*block) <= budget)
+        .max_by_key(|(block, lot)| (map.get_land_value(*block), Reverse((*block, *lot))))
+        .or_else(|| {
+            lots.iter()
+                .min_by_key(|(block, lot)| (map.get_land_value(*block), *block, *lot))
+        })
+        .copied()
+}
+
+fn generate_street_names(
+    generator: &StreetNameGenerator,
+    count: usize,
+    offset: usize,
+    suffix: &str,
+    used: &mut HashSet<String>,
+) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for index in 0..count {
+        let name = generator.generate(index * 2 + offset, suffix, used);
+        used.insert(name.clone());
+        names.push(name);
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::town::district::District;
+
+    #[test]
+    fn generate_unique_street_names() {
+        let mut map = TownMap::empty(1, 1);
+
+        set_generated_street_names(&mut map, &StreetNameGenerator::default());
+
+        assert_eq!(map.horizontal_streets(), &vec!["A Street", "C Street"]);
+        assert_eq!(map.vertical_streets(), &vec!["B Road", "A Road"]);
+    }
+
+    #[test]
+    fn find_best_lot_for_budget() {
+        let map = TownMap::empty(3, 3);
+        let house = BuildingUsage::house();
+        let apartments = BuildingUsage::apartments(4);
+
+        assert_eq!(find_best_lot(&map, &house, 100), Some((4, 0)));
+        assert_eq!(find_best_lot(&map, &house, 60), Some((0, 0)));
+        assert_eq!(find_best_lot(&map, &house, 10), Some((0, 0)));
+        assert_eq!(find_best_lot(&map, &apartments, 70), Some((1, 0)));
+        assert_eq!(find_best_lot(&map, &apartments, 10), Some((1, 0)));
+    }
+
+    #[test]
+    fn find_no_lot_without_zoning() {
+        let mut map = TownMap::empty(1, 1);
+        map.set_district(0, District::Market);
+
+        assert_eq!(find_best_lot(&map, &BuildingUsage::house(), 100), None);
+    }
+
+    #[test]
+    fn grow_map_until_max_size() {
+        let generator = StreetNameGenerator::default();
+        let mut map = TownMap::empty(1, 1);
+        map.set_max_size(2, 3);
+
+        assert!(grow_map(&mut map, &generator));
+        assert_eq!((*map.width(), *map.height()), (1, 2));
+        assert!(grow_map(&mut map, &generator));
+        assert_eq!((*map.width(), *map.height()), (2, 2));
+        assert!(grow_map(&mut map, &generator));
+        assert_eq!((*map.width(), *map.height()), (2, 3));
+        assert!(!grow_map(&mut map, &generator));
+
+        assert_eq!(map.horizontal_streets().len(), 4);
+        assert_eq!(map.vertical_streets().len(), 3);
+    }
+}
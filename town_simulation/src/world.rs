@@ -0,0 +1,240 @@
+use crate::model::building::BuildingMgr;
+use crate::model::character::CharacterId;
+use crate::model::town::map::TownMap;
+use crate::model::town::TownId;
+use crate::simulation::building::relocate;
+use crate::usecase::building::occupancy::remove_occupant_from_building;
+use crate::usecase::building::ownership::{get_buildings_owned_by, get_owner, update_owner};
+use crate::SimulationData;
+use derive_getters::Getters;
+use std::collections::HashSet;
+use std::mem::swap;
+
+#[derive(Getters, Clone, Debug, Default)]
+pub struct Town {
+    name: String,
+    /// Empty, while the town is selected.
+    map: TownMap,
+    /// Empty, while the town is selected.
+    building_manager: BuildingMgr,
+}
+
+/// Several towns sharing the characters, the date & the seed of the world.
+/// The map & buildings of the selected town are moved into the [`SimulationData`],
+/// so it can be simulated & viewed like a single town.
+#[derive(Getters, Clone, Debug)]
+pub struct World {
+    seed: u64,
+    towns: Vec<Town>,
+    data: SimulationData,
+}
+
+impl World {
+    /// The map & buildings of the data become the first town.
+    pub fn new<S: Into<String>>(seed: u64, name: S, mut data: SimulationData) -> Self {
+        data.town = TownId::default();
+
+        Self {
+            seed,
+            towns: vec![Town {
+                name: name.into(),
+                ..Town::default()
+            }],
+            data,
+        }
+    }
+
+    pub fn add_town<S: Into<String>>(&mut self, name: S, map: TownMap) -> TownId {
+        let id = TownId::new(self.towns.len());
+
+        self.towns.push(Town {
+            name: name.into(),
+            map,
+            building_manager: BuildingMgr::default(),
+        });
+
+        id
+    }
+
+    pub fn get_town_ids(&self) -> Vec<TownId> {
+        (0..self.towns.len()).map(TownId::new).collect()
+    }
+
+    pub fn get_name(&self, town: TownId) -> &str {
+        &self.towns[town.id()].name
+    }
+
+    pub fn get_selected_town(&self) -> TownId {
+        self.data.town
+    }
+
+    pub fn get_map(&self, town: TownId) -> &TownMap {
+        if town == self.data.town {
+            &self.data.map
+        } else {
+            &self.towns[town.id()].map
+        }
+    }
+
+    pub fn get_building_manager(&self, town: TownId) -> &BuildingMgr {
+        if town == self.data.town {
+            &self.data.building_manager
+        } else {
+            &self.towns[town.id()].building_manager
+        }
+    }
+
+    pub fn get_data_mut(&mut self) -> &mut SimulationData {
+        &mut self.data
+    }
+
+    pub fn get_residents(&self, town: TownId) -> HashSet<CharacterId> {
+        self.data
+            .character_manager
+            .get_all()
+            .iter()
+            .filter(|character| character.lives_in(town))
+            .map(|character| *character.id())
+            .collect()
+    }
+
+    /// Moves the map & buildings of the town into the simulation data.
+    pub fn select(&mut self, town: TownId) {
+        if town == self.data.town {
+            return;
+        }
+
+        let previous = &mut self.towns[self.data.town.id()];
+        swap(&mut previous.map, &mut self.data.map);
+        swap(
+            &mut previous.building_manager,
+            &mut self.data.building_manager,
+        );
+
+        let next = &mut self.towns[town.id()];
+        swap(&mut next.map, &mut self.data.map);
+        swap(&mut next.building_manager, &mut self.data.building_manager);
+
+        self.data.town = town;
+    }
+}
+
+/// The characters leave their homes & property behind, move to the other town & search for a new home together.
+pub fn migrate(world: &mut World, ids: Vec<CharacterId>, to: TownId) {
+    for &id in &ids {
+        leave_town(world, id, to);
+    }
+
+    world.select(to);
+    relocate(&mut world.data, ids);
+}
+
+/// The character sells its share of buildings to the other owners or the town, before it moves to the other town.
+pub fn leave_town(world: &mut World, id: CharacterId, to: TownId) {
+    let from = *world.data.character_manager.get(id).unwrap().town();
+    world.select(from);
+    let data = &mut world.data;
+
+    for building_id in get_buildings_owned_by(&data.character_manager, id) {
+        let owner = get_owner(&data.building_manager, building_id).pass_share(id, &[]);
+        update_owner(data, building_id, owner);
+    }
+
+    remove_occupant_from_building(data, id);
+    data.character_manager.get_mut(id).unwrap().immigrate(to);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::building::owner::Owner;
+    use crate::model::building::usage::BuildingUsage;
+    use crate::usecase::building::build::build;
+    use crate::usecase::building::occupancy::{get_building_occupied_by, get_occupants};
+    use crate::usecase::building::relocate::relocate_to_house;
+    use crate::util::assert::assert;
+
+    #[test]
+    fn select_town() {
+        let mut world = World::new(42, "Town", SimulationData::default());
+        let village = world.add_town("Village", TownMap::empty(1, 1));
+        let builder = world.data.character_manager.create();
+        build(
+            &mut world.data,
+            0,
+            0,
+            BuildingUsage::house(),
+            builder,
+            builder,
+        );
+
+        world.select(village);
+
+        assert_eq!(world.get_selected_town(), village);
+        assert_eq!(*world.data.map.width(), 1);
+        assert!(world.data.building_manager.get_all().is_empty());
+        assert_eq!(
+            world
+                .get_building_manager(TownId::default())
+                .get_all()
+                .len(),
+            1
+        );
+
+        world.select(TownId::default());
+
+        assert_eq!(*world.data.map.width(), 2);
+        assert_eq!(world.data.building_manager.get_all().len(), 1);
+        assert_eq!(world.get_name(village), "Village");
+    }
+
+    #[test]
+    fn migrants_sell_their_property() {
+        let mut world = World::new(42, "Town", SimulationData::default());
+        let village = world.add_town("Village", TownMap::empty(2, 2));
+        let migrant = world.data.character_manager.create();
+        let spouse = world.data.character_manager.create();
+        let stays = world.data.character_manager.create();
+        let house = build(
+            &mut world.data,
+            0,
+            0,
+            BuildingUsage::house(),
+            migrant,
+            migrant,
+        );
+        let shared = build(
+            &mut world.data,
+            1,
+            0,
+            BuildingUsage::house(),
+            migrant,
+            stays,
+        );
+        update_owner(
+            &mut world.data,
+            shared,
+            Owner::from_characters(vec![migrant, stays]),
+        );
+        relocate_to_house(&mut world.data, vec![migrant, stays], house);
+
+        migrate(&mut world, vec![migrant, spouse], village);
+
+        assert_eq!(world.get_selected_town(), village);
+        assert(world.get_residents(village), [migrant, spouse]);
+        assert(world.get_residents(TownId::default()), [stays]);
+        assert_eq!(
+            get_building_occupied_by(&world.data.character_manager, stays),
+            Some(house)
+        );
+
+        world.select(TownId::default());
+
+        assert_eq!(get_owner(&world.data.building_manager, house), &Owner::Town);
+        assert_eq!(
+            get_owner(&world.data.building_manager, shared),
+            &Owner::Character(stays)
+        );
+        assert(get_occupants(&world.data.building_manager, house), [stays]);
+    }
+}
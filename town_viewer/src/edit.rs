@@ -0,0 +1,395 @@
+use town_simulation::model::building::owner::Owner;
+use town_simulation::model::building::BuildingId;
+use town_simulation::model::character::gender::Gender::Male;
+use town_simulation::model::character::name::CharacterName;
+use town_simulation::model::character::{Character, CharacterId};
+use town_simulation::model::inheritance::InheritanceLaw;
+use town_simulation::simulation::building::relocate;
+use town_simulation::usecase::building::ownership::update_owner;
+use town_simulation::usecase::building::relocate::relocate_to_house;
+use town_simulation::usecase::character::birth::birth;
+use town_simulation::usecase::character::death::death;
+use town_simulation::usecase::character::inheritance::set_testament;
+use town_simulation::usecase::character::marriage::{is_married, wed};
+use town_simulation::usecase::character::relation::get::{
+    get_children, get_relatives, get_spouses,
+};
+use town_simulation::usecase::character::set_name;
+use town_simulation::world::World;
+
+const ADULT_AGE: u32 = 18;
+
+/// The error of a manual change, which is shown to the game master.
+pub type EditResult<T> = Result<T, String>;
+
+/// Parses character ids separated by commas or spaces.
+pub fn parse_character_ids(text: &str) -> EditResult<Vec<CharacterId>> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.parse()
+                .map(CharacterId::new)
+                .map_err(|_| format!("'{}' is no character id!", part))
+        })
+        .collect()
+}
+
+fn get_character(world: &World, id: CharacterId) -> EditResult<&Character> {
+    world
+        .data()
+        .character_manager
+        .get(id)
+        .ok_or_else(|| format!("Character {} is unknown!", id.id()))
+}
+
+fn get_resident(world: &World, id: CharacterId) -> EditResult<&Character> {
+    let character = get_character(world, id)?;
+
+    if !character.is_alive() {
+        return Err(format!("{} is dead!", character.name()));
+    } else if character.has_emigrated() {
+        return Err(format!("{} has emigrated!", character.name()));
+    }
+
+    Ok(character)
+}
+
+fn get_resident_of_selected_town(world: &World, id: CharacterId) -> EditResult<&Character> {
+    let character = get_resident(world, id)?;
+    let town = world.get_selected_town();
+
+    if *character.town() != town {
+        return Err(format!(
+            "{} doesn't live in {}!",
+            character.name(),
+            world.get_name(town)
+        ));
+    }
+
+    Ok(character)
+}
+
+fn get_adult(world: &World, id: CharacterId) -> EditResult<&Character> {
+    let character = get_resident(world, id)?;
+
+    if character.get_age(world.data().date) < ADULT_AGE {
+        return Err(format!("{} is not an adult!", character.name()));
+    }
+
+    Ok(character)
+}
+
+/// Checks that both characters live in the same town & selects it.
+fn select_town_of_both(world: &mut World, id0: CharacterId, id1: CharacterId) -> EditResult<()> {
+    let character0 = get_character(world, id0)?;
+    let character1 = get_character(world, id1)?;
+    let town = *character0.town();
+
+    if town != *character1.town() {
+        return Err(format!(
+            "{} & {} live in different towns!",
+            character0.name(),
+            character1.name()
+        ));
+    }
+
+    world.select(town);
+    Ok(())
+}
+
+/// An empty last name creates a simple name.
+pub fn rename(world: &mut World, id: CharacterId, first: &str, last: &str) -> EditResult<()> {
+    get_character(world, id)?;
+
+    let first = first.trim();
+    let last = last.trim();
+
+    if first.is_empty() {
+        return Err("The first name is missing!".to_string());
+    }
+
+    let name = if last.is_empty() {
+        CharacterName::simple(first)
+    } else {
+        CharacterName::standard(first, last)
+    };
+
+    set_name(&mut world.get_data_mut().character_manager, id, name);
+    Ok(())
+}
+
+/// Selects the town of the character, because its buildings are inherited.
+pub fn kill(world: &mut World, id: CharacterId) -> EditResult<()> {
+    let town = *get_resident(world, id)?.town();
+
+    world.select(town);
+    death(world.get_data_mut(), id);
+    Ok(())
+}
+
+/// The couple moves into a new home like after a simulated marriage.
+pub fn arrange_marriage(world: &mut World, id: CharacterId, spouse: CharacterId) -> EditResult<()> {
+    let manager = &world.data().character_manager;
+    let character = get_adult(world, id)?;
+    let other = get_adult(world, spouse)?;
+
+    if id == spouse {
+        return Err(format!("{} can't marry themselves!", character.name()));
+    } else if !character.gender().is_reverse(*other.gender()) {
+        return Err(format!(
+            "{} & {} have the same gender!",
+            character.name(),
+            other.name()
+        ));
+    } else if let Some(married) = [character, other]
+        .into_iter()
+        .find(|married| is_married(manager, *married.id()))
+    {
+        return Err(format!("{} is already married!", married.name()));
+    } else if get_relatives(manager, id).contains(&spouse) {
+        return Err(format!(
+            "{} & {} are relatives!",
+            character.name(),
+            other.name()
+        ));
+    }
+
+    let (husband, wife) = if *character.gender() == Male {
+        (id, spouse)
+    } else {
+        (spouse, id)
+    };
+
+    select_town_of_both(world, husband, wife)?;
+    wed(world.get_data_mut(), husband, wife);
+    relocate(world.get_data_mut(), vec![husband, wife]);
+    Ok(())
+}
+
+/// The child joins the home of its parents.
+pub fn have_child(
+    world: &mut World,
+    id: CharacterId,
+    partner: CharacterId,
+) -> EditResult<CharacterId> {
+    let character = get_adult(world, id)?;
+    let other = get_adult(world, partner)?;
+
+    if !get_spouses(&world.data().character_manager, id).contains(&partner) {
+        return Err(format!(
+            "{} & {} are not married!",
+            character.name(),
+            other.name()
+        ));
+    }
+
+    select_town_of_both(world, id, partner)?;
+    Ok(birth(world.get_data_mut(), id, partner))
+}
+
+/// The building of the selected town is transferred to the characters or the town, if there are none.
+pub fn transfer_building(
+    world: &mut World,
+    building: BuildingId,
+    owners: Vec<CharacterId>,
+) -> EditResult<()> {
+    match world.data().building_manager.get(building) {
+        None => return Err(format!("Building {} is unknown!", building.id())),
+        Some(building) if building.is_demolished() => {
+            return Err(format!("Building {} is demolished!", building.id().id()))
+        }
+        _ => {}
+    }
+
+    for owner in &owners {
+        get_resident_of_selected_town(world, *owner)?;
+    }
+
+    update_owner(
+        world.get_data_mut(),
+        building,
+        Owner::from_characters(owners),
+    );
+    Ok(())
+}
+
+/// The character, their living spouses & minor children move into an empty home of the building in the selected town.
+pub fn move_family(world: &mut World, id: CharacterId, building: BuildingId) -> EditResult<()> {
+    let data = world.data();
+    let town = world.get_selected_town();
+    get_resident_of_selected_town(world, id)?;
+
+    match data.building_manager.get(building) {
+        None => return Err(format!("Building {} is unknown!", building.id())),
+        Some(home) if home.is_under_construction() => {
+            return Err(format!("Building {} is under construction!", building.id()))
+        }
+        Some(home) if home.is_demolished() || !home.usage().has_empty_home() => {
+            return Err(format!("Building {} has no empty home!", building.id()))
+        }
+        _ => {}
+    }
+
+    let manager = &data.character_manager;
+    let children = get_children(manager, id)
+        .into_iter()
+        .filter(|child| manager.get(*child).unwrap().get_age(data.date) < ADULT_AGE);
+    let mut family = vec![id];
+    family.extend(
+        get_spouses(manager, id)
+            .into_iter()
+            .chain(children)
+            .filter(|member| manager.get(*member).unwrap().lives_in(town)),
+    );
+    family.sort_by_key(|member| member.id());
+    family.dedup();
+
+    relocate_to_house(world.get_data_mut(), family, building);
+    Ok(())
+}
+
+/// The law applies to all towns from the next death on.
+pub fn change_inheritance_law(world: &mut World, name: &str) -> EditResult<()> {
+    let law =
+        InheritanceLaw::parse(name).ok_or_else(|| format!("'{}' is no inheritance law!", name))?;
+
+    world.get_data_mut().inheritance_law = law;
+    Ok(())
+}
+
+/// Names the heir of the character or revokes the testament, if there is none.
+pub fn write_testament(
+    world: &mut World,
+    id: CharacterId,
+    heir: Option<CharacterId>,
+) -> EditResult<()> {
+    let character = get_resident(world, id)?;
+
+    if let Some(heir) = heir {
+        let other = get_resident(world, heir)?;
+
+        if id == heir {
+            return Err(format!(
+                "{} can't inherit from themselves!",
+                character.name()
+            ));
+        } else if character.town() != other.town() {
+            return Err(format!(
+                "{} & {} live in different towns!",
+                character.name(),
+                other.name()
+            ));
+        }
+    }
+
+    set_testament(&mut world.get_data_mut().character_manager, id, heir);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use town_simulation::model::building::usage::BuildingUsage;
+    use town_simulation::model::time::Date;
+    use town_simulation::model::town::map::TownMap;
+    use town_simulation::usecase::building::build::{build, start_construction};
+    use town_simulation::usecase::building::occupancy::get_building_occupied_by;
+    use town_simulation::usecase::character::inheritance::get_testament;
+    use town_simulation::SimulationData;
+
+    fn create_world() -> (World, CharacterId, BuildingId, BuildingId) {
+        let mut data = SimulationData {
+            date: Date::new(30),
+            map: TownMap::empty(3, 3),
+            ..SimulationData::default()
+        };
+        let id = data.character_manager.create();
+        let house = build(&mut data, 0, 0, BuildingUsage::house(), id, id);
+        let site = start_construction(&mut data, 2, 0, BuildingUsage::house(), id, id, vec![]);
+
+        (World::new(0, "Town", data), id, house, site)
+    }
+
+    #[test]
+    fn test_parse_character_ids() {
+        assert_eq!(
+            parse_character_ids("1, 2 3"),
+            Ok(vec![1, 2, 3].into_iter().map(CharacterId::new).collect())
+        );
+        assert_eq!(
+            parse_character_ids("1,a"),
+            Err("'a' is no character id!".to_string())
+        );
+    }
+
+    #[test]
+    fn move_family_into_house() {
+        let (mut world, id, house, _) = create_world();
+
+        assert_eq!(move_family(&mut world, id, house), Ok(()));
+        assert_eq!(
+            get_building_occupied_by(&world.data().character_manager, id),
+            Some(house)
+        );
+        assert!(move_family(&mut world, id, house).is_err());
+    }
+
+    #[test]
+    fn move_family_into_construction_site() {
+        let (mut world, id, _, site) = create_world();
+
+        assert_eq!(
+            move_family(&mut world, id, site),
+            Err(format!("Building {} is under construction!", site.id()))
+        );
+        assert_eq!(
+            get_building_occupied_by(&world.data().character_manager, id),
+            None
+        );
+    }
+
+    #[test]
+    fn move_family_into_unknown_building() {
+        let (mut world, id, _, _) = create_world();
+
+        assert_eq!(
+            move_family(&mut world, id, BuildingId::new(9)),
+            Err("Building 9 is unknown!".to_string())
+        );
+    }
+
+    #[test]
+    fn test_change_inheritance_law() {
+        let (mut world, _, _, _) = create_world();
+
+        assert_eq!(change_inheritance_law(&mut world, "testament"), Ok(()));
+        assert_eq!(world.data().inheritance_law, InheritanceLaw::Testament);
+        assert_eq!(
+            change_inheritance_law(&mut world, "lottery"),
+            Err("'lottery' is no inheritance law!".to_string())
+        );
+        assert_eq!(world.data().inheritance_law, InheritanceLaw::Testament);
+    }
+
+    #[test]
+    fn write_and_revoke_testament() {
+        let (mut world, id, _, _) = create_world();
+        let heir = world.get_data_mut().character_manager.create();
+
+        assert_eq!(write_testament(&mut world, id, Some(heir)), Ok(()));
+        assert_eq!(
+            get_testament(&world.data().character_manager, id),
+            Some(heir)
+        );
+        assert_eq!(write_testament(&mut world, id, None), Ok(()));
+        assert_eq!(get_testament(&world.data().character_manager, id), None);
+    }
+
+    #[test]
+    fn write_testament_for_themselves() {
+        let (mut world, id, _, _) = create_world();
+
+        assert!(write_testament(&mut world, id, Some(id)).is_err());
+        assert_eq!(get_testament(&world.data().character_manager, id), None);
+    }
+}
@@ -1,42 +1,197 @@
+use rocket::FromForm;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
 use town_simulation::generation::name::character::CharacterNameGenerator;
+use town_simulation::generation::name::street::StreetNameGenerator;
+use town_simulation::generation::number::RandomNumberGenerator;
+use town_simulation::generation::terrain::generate_terrain;
+use town_simulation::model::building::usage::BuildingUsage;
 use town_simulation::model::building::BuildingMgr;
 use town_simulation::model::character::{CharacterId, CharacterMgr};
+use town_simulation::model::inheritance::InheritanceLaw;
 use town_simulation::model::time::Date;
 use town_simulation::model::town::map::TownMap;
-use town_simulation::simulation::simulate_year;
+use town_simulation::model::town::TownId;
+use town_simulation::usecase::building::build::build_for_town;
 use town_simulation::usecase::character::birth::set_birth_date;
 use town_simulation::usecase::character::{set_gender_based_on_id, set_generated_name};
+use town_simulation::usecase::town::set_generated_street_names;
+use town_simulation::world::World;
 use town_simulation::SimulationData;
 
-pub fn init_simulation(mut start_date: Date, years: u32, characters: u32) -> SimulationData {
-    let character_name_generator = CharacterNameGenerator::load("resources/names/english");
-    let character_manager = init_characters(&character_name_generator, start_date, characters);
+const NAMES_DIR: &str = "resources/names";
+const FOUNDER_AGE: u32 = 20;
 
-    start_date.increase_by(20);
+/// The settings of a new world, which are chosen in the new-town wizard.
+#[derive(FromForm, Clone, Debug, PartialEq, Eq)]
+pub struct TownSettings {
+    pub name: String,
+    pub seed: u64,
+    /// The first simulated year. The founders are born 20 years earlier.
+    pub start_year: u32,
+    pub founders: u32,
+    pub warm_up_years: u32,
+    /// The width & height of the map of the town in blocks.
+    pub size: usize,
+    /// The villages share a fifth of the founders.
+    pub villages: u32,
+    /// The prefix of the name files in the resources.
+    pub culture: String,
+}
+
+impl TownSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("The town needs a name!".to_string());
+        } else if !(5..=1000).contains(&self.founders) {
+            return Err("The number of founders must be between 5 & 1000!".to_string());
+        } else if !(1..=20).contains(&self.size) {
+            return Err("The map size must be between 1 & 20!".to_string());
+        } else if self.villages > 5 {
+            return Err("At most 5 villages are allowed!".to_string());
+        } else if self.warm_up_years > 1000 {
+            return Err("At most 1000 warm-up years are allowed!".to_string());
+        } else if !(FOUNDER_AGE..=5000).contains(&self.start_year) {
+            return Err(format!(
+                "The start year must be between {} & 5000!",
+                FOUNDER_AGE
+            ));
+        } else if !get_cultures().contains(&self.culture) {
+            return Err(format!("The name culture '{}' is unknown!", self.culture));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for TownSettings {
+    fn default() -> Self {
+        Self {
+            name: "Kingsbridge".to_string(),
+            seed: 42,
+            start_year: 1820,
+            founders: 50,
+            warm_up_years: 100,
+            size: 7,
+            villages: 1,
+            culture: "english".to_string(),
+        }
+    }
+}
+
+pub fn get_cultures() -> Vec<String> {
+    let mut cultures: Vec<String> = fs::read_dir(NAMES_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter_map(|file| file.strip_suffix("-male.csv").map(str::to_string))
+                .filter(|culture| {
+                    ["female", "family", "street"].iter().all(|kind| {
+                        Path::new(&format!("{}-{}.csv", get_name_files(culture), kind)).exists()
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    cultures.sort();
+    cultures
+}
+
+fn get_name_files(culture: &str) -> String {
+    format!("{}/{}", NAMES_DIR, culture)
+}
+
+/// Creates the world with a town & its villages. The warm-up years are simulated later.
+pub fn init_simulation(settings: &TownSettings) -> World {
+    let culture = get_name_files(&settings.culture);
+    let character_name_generator = CharacterNameGenerator::load(&culture);
+    let mut character_manager = CharacterMgr::default();
+    let street_name_generator = StreetNameGenerator::load(&culture);
+    let characters = settings.founders;
+    let village_characters = characters / 5 / settings.villages.max(1);
+    let seed = settings.seed;
+    let size = settings.size;
+    let town_map = init_map(&street_name_generator, size, size + 2, seed);
+    let birth_date = Date::new(settings.start_year - FOUNDER_AGE);
+    let mut start_date = birth_date;
+
+    init_characters(
+        &mut character_manager,
+        &character_name_generator,
+        birth_date,
+        characters - village_characters * settings.villages,
+        TownId::default(),
+    );
+    start_date.increase_by(FOUNDER_AGE);
 
-    let mut simulation_data = SimulationData {
+    let simulation_data = SimulationData {
         building_manager: BuildingMgr::default(),
         character_manager,
         character_name_generator,
         date: start_date,
-        map: TownMap::empty(6, 5),
+        inheritance_law: InheritanceLaw::default(),
+        map: town_map,
+        street_name_generator,
+        town: TownId::default(),
+        events: Vec::new(),
     };
 
-    for _i in 0..years {
-        simulate_year(&mut simulation_data);
+    let mut world = World::new(seed, settings.name.trim(), simulation_data);
+    let mut used_names = HashSet::from([settings.name.trim().to_string()]);
+
+    for i in 0..settings.villages {
+        let village_seed = seed.wrapping_add(i as u64 + 1);
+        let data = world.data();
+        let name = data.street_name_generator.generate(
+            village_seed as u32 as usize,
+            "Village",
+            &used_names,
+        );
+        let village_map = init_map(&data.street_name_generator, 3, 5, village_seed);
+        used_names.insert(name.clone());
+        let village = world.add_town(name, village_map);
+        let data = world.get_data_mut();
+
+        init_characters(
+            &mut data.character_manager,
+            &data.character_name_generator,
+            birth_date,
+            village_characters,
+            village,
+        );
     }
 
-    simulation_data
+    let data = world.get_data_mut();
+    let centre = data.map.get_centre_block();
+    build_for_town(data, centre, 0, BuildingUsage::Temple, CharacterId::new(0));
+
+    world
 }
 
-fn init_characters(names: &CharacterNameGenerator, date: Date, characters: u32) -> CharacterMgr {
-    let mut manager = CharacterMgr::default();
+fn init_map(generator: &StreetNameGenerator, size: usize, max_size: usize, seed: u64) -> TownMap {
+    let mut map = TownMap::empty(size, size);
 
+    map.set_max_size(max_size, max_size);
+    generate_terrain(&mut map, &RandomNumberGenerator::Seeded(seed));
+    set_generated_street_names(&mut map, generator);
+
+    map
+}
+
+fn init_characters(
+    manager: &mut CharacterMgr,
+    names: &CharacterNameGenerator,
+    date: Date,
+    characters: u32,
+    town: TownId,
+) {
     for _i in 0..characters {
-        init_character(&mut manager, names, date);
+        let id = init_character(manager, names, date);
+        manager.get_mut(id).unwrap().set_town(town);
     }
-
-    manager
 }
 
 fn init_character(
@@ -1,85 +1,496 @@
 #[macro_use]
 extern crate rocket;
 
-use crate::init::init_simulation;
-use crate::visualize::building::{visualize_building, visualize_buildings};
-use crate::visualize::character::{visualize_character, visualize_characters};
+use crate::edit::{
+    arrange_marriage, change_inheritance_law, have_child, kill, move_family, parse_character_ids,
+    rename, transfer_building, write_testament, EditResult,
+};
+use crate::init::{get_cultures, init_simulation, TownSettings};
+use crate::run::{is_running, start_run, stop_run, Progress};
+use crate::visualize::building::{visualize_building, visualize_buildings, BuildingQuery};
+use crate::visualize::character::{visualize_character, visualize_characters, CharacterQuery};
+use crate::visualize::family::{visualize_families, visualize_family};
+use crate::visualize::new_town::visualize_new_town;
+use crate::visualize::statistics::visualize_statistics;
+use crate::visualize::svg::{render_map, MapColoring};
 use crate::visualize::town::visualize_town;
-use crate::visualize::visualize_overview;
+use crate::visualize::{visualize_error, visualize_overview, visualize_progress};
+use rocket::form::Form;
 use rocket::fs::FileServer;
-use rocket::response::content::Html;
+use rocket::http::ContentType;
+use rocket::response::content::RawHtml;
 use rocket::response::Redirect;
 use rocket::State;
-use std::sync::Mutex;
-use town_simulation::model::time::Date;
-use town_simulation::simulation::simulate_year;
-use town_simulation::SimulationData;
+use std::sync::{Arc, Mutex};
+use town_simulation::biography::to_markdown;
+use town_simulation::generation::number::RandomNumberGenerator;
+use town_simulation::history::History;
+use town_simulation::model::building::BuildingId;
+use town_simulation::model::character::CharacterId;
+use town_simulation::model::town::TownId;
+use town_simulation::simulation::pipeline::SimulationPipeline;
+use town_simulation::simulation::run::StopCondition;
+use town_simulation::simulation::world::simulate_world_year;
+use town_simulation::statistics::{get_yearly_statistics, to_csv};
+use town_simulation::world::World;
 
+pub mod edit;
 pub mod init;
+pub mod run;
 pub mod visualize;
 
-struct ViewerData {
-    data: Mutex<SimulationData>,
+/// The world is always locked before the history & the history before the progress.
+pub struct ViewerData {
+    world: Arc<Mutex<World>>,
+    history: Arc<Mutex<History>>,
+    progress: Arc<Mutex<Progress>>,
 }
 
 #[get("/")]
-fn get_overview(data: &State<ViewerData>) -> Html<String> {
-    let data = data.data.lock().expect("lock shared data");
-    Html(visualize_overview(&data))
+fn get_overview(data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    let history = data.history.lock().expect("lock shared history");
+    let progress = data.progress.lock().expect("lock shared progress");
+    RawHtml(visualize_overview(&world, &history.get_years(), &progress))
 }
 
-#[get("/simulate")]
+#[get("/")]
 fn simulate(data: &State<ViewerData>) -> Redirect {
-    let mut data = data.data.lock().expect("lock shared data");
-    simulate_year(&mut data);
+    if is_running(data) {
+        return Redirect::to(uri!("/simulate", get_progress()));
+    }
+
+    let mut world = data.world.lock().expect("lock shared world");
+    let mut history = data.history.lock().expect("lock shared history");
+    history.record(&world);
+    let mut pipeline = SimulationPipeline::new(RandomNumberGenerator::Seeded(*world.seed()));
+    simulate_world_year(&mut world, &mut pipeline);
     Redirect::to(uri!(get_overview()))
 }
 
+#[get("/run?<years>&<target>&<min_population>&<max_population>")]
+fn run_simulation(
+    years: Option<u32>,
+    target: Option<u32>,
+    min_population: Option<usize>,
+    max_population: Option<usize>,
+    data: &State<ViewerData>,
+) -> Redirect {
+    let year = data
+        .world
+        .lock()
+        .expect("lock shared world")
+        .data()
+        .date
+        .get_year();
+    let target_year = target.unwrap_or(year + years.unwrap_or(1));
+    let conditions = min_population
+        .map(StopCondition::PopulationBelow)
+        .into_iter()
+        .chain(max_population.map(StopCondition::PopulationAbove))
+        .collect();
+
+    start_run(data, target_year, conditions);
+
+    Redirect::to(uri!("/simulate", get_progress()))
+}
+
+#[get("/progress")]
+fn get_progress(data: &State<ViewerData>) -> RawHtml<String> {
+    let progress = data.progress.lock().expect("lock shared progress");
+    RawHtml(visualize_progress(&progress))
+}
+
+#[get("/stop")]
+fn stop_simulation(data: &State<ViewerData>) -> Redirect {
+    stop_run(data);
+    Redirect::to(uri!("/simulate", get_progress()))
+}
+
+#[post("/rewind", data = "<form>")]
+fn rewind(form: Form<YearForm>, data: &State<ViewerData>) -> Redirect {
+    if is_running(data) {
+        return Redirect::to(uri!("/simulate", get_progress()));
+    }
+
+    let mut world = data.world.lock().expect("lock shared world");
+    let mut history = data.history.lock().expect("lock shared history");
+
+    if let Some(snapshot) = history.rewind(form.year) {
+        *world = snapshot;
+    }
+
+    Redirect::to(uri!(get_overview()))
+}
+
+/// Simulates the last year again with a different seed.
+#[post("/reroll", data = "<form>")]
+fn reroll(form: Form<SeedForm>, data: &State<ViewerData>) -> Redirect {
+    if is_running(data) {
+        return Redirect::to(uri!("/simulate", get_progress()));
+    }
+
+    let mut world = data.world.lock().expect("lock shared world");
+    let mut history = data.history.lock().expect("lock shared history");
+    let last_year = world.data().date.get_year() - 1;
+
+    if let Some(snapshot) = history.rewind(last_year) {
+        *world = snapshot;
+        let mut pipeline = SimulationPipeline::new(RandomNumberGenerator::Seeded(form.seed));
+        simulate_world_year(&mut world, &mut pipeline);
+    }
+
+    Redirect::to(uri!(get_overview()))
+}
+
+#[post("/law", data = "<form>")]
+fn change_law(form: Form<LawForm>, data: &State<ViewerData>) -> Result<Redirect, RawHtml<String>> {
+    match edit_world(data, |world| change_inheritance_law(world, &form.law)) {
+        Ok(()) => Ok(Redirect::to(uri!(get_overview()))),
+        Err(error) => Err(RawHtml(visualize_error(&error, "/"))),
+    }
+}
+
 #[get("/")]
-fn get_buildings(data: &State<ViewerData>) -> Html<String> {
-    let data = data.data.lock().expect("lock shared data");
-    Html(visualize_buildings(&data))
+fn get_statistics(data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    let history = data.history.lock().expect("lock shared history");
+    let statistics = get_yearly_statistics(&history, &world);
+    RawHtml(visualize_statistics(&world, &statistics))
 }
 
-#[get("/<id>")]
-fn get_building(id: usize, data: &State<ViewerData>) -> Html<String> {
-    let data = data.data.lock().expect("lock shared data");
-    Html(visualize_building(&data, id))
+#[get("/yearly.csv")]
+fn get_statistics_csv(data: &State<ViewerData>) -> (ContentType, String) {
+    let world = data.world.lock().expect("lock shared world");
+    let history = data.history.lock().expect("lock shared history");
+    (
+        ContentType::CSV,
+        to_csv(&get_yearly_statistics(&history, &world)),
+    )
 }
 
 #[get("/")]
-fn get_characters(data: &State<ViewerData>) -> Html<String> {
-    let data = data.data.lock().expect("lock shared data");
-    Html(visualize_characters(&data))
+fn get_new_town() -> RawHtml<String> {
+    RawHtml(visualize_new_town(
+        &TownSettings::default(),
+        &get_cultures(),
+        None,
+    ))
+}
+
+/// Replaces the world & its history with a new one & simulates the warm-up years in the background.
+#[post("/", data = "<settings>")]
+fn create_new_town(
+    settings: Form<TownSettings>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    let error = if is_running(data) {
+        Err("Stop the running simulation first!".to_string())
+    } else {
+        settings.validate()
+    };
+
+    if let Err(error) = error {
+        return Err(RawHtml(visualize_new_town(
+            &settings,
+            &get_cultures(),
+            Some(&error),
+        )));
+    }
+
+    {
+        let mut world = data.world.lock().expect("lock shared world");
+        let mut history = data.history.lock().expect("lock shared history");
+        *world = init_simulation(&settings);
+        *history = History::default();
+    }
+
+    start_run(
+        data,
+        settings.start_year + settings.warm_up_years,
+        Vec::new(),
+    );
+
+    Ok(Redirect::to(uri!("/simulate", get_progress())))
+}
+
+#[get("/?<sort>")]
+fn get_families(sort: Option<&str>, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_families(&world, sort))
+}
+
+#[get("/<name>")]
+fn get_family(name: &str, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_family(&world, name))
+}
+
+#[get("/?<query..>")]
+fn get_buildings(query: BuildingQuery, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_buildings(world.data(), &query))
 }
 
 #[get("/<id>")]
-fn get_character(id: usize, data: &State<ViewerData>) -> Html<String> {
-    let data = data.data.lock().expect("lock shared data");
-    Html(visualize_character(&data, id))
+fn get_building(id: usize, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_building(world.data(), id))
 }
 
-#[get("/")]
-fn get_town(data: &State<ViewerData>) -> Html<String> {
-    let data = data.data.lock().expect("lock shared data");
-    Html(visualize_town(&data))
+#[get("/?<query..>")]
+fn get_characters(query: CharacterQuery, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_characters(world.data(), &query))
+}
+
+#[get("/<id>")]
+fn get_character(id: usize, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_character(&world, id))
+}
+
+#[get("/<id>/biography.md")]
+fn get_biography_markdown(id: usize, data: &State<ViewerData>) -> Option<(ContentType, String)> {
+    let world = data.world.lock().expect("lock shared world");
+    let id = CharacterId::new(id);
+    world.data().character_manager.get(id)?;
+    Some((ContentType::Markdown, to_markdown(&world, id)))
+}
+
+#[get("/?<color>")]
+fn get_town(color: Option<&str>, data: &State<ViewerData>) -> RawHtml<String> {
+    let world = data.world.lock().expect("lock shared world");
+    RawHtml(visualize_town(world.data(), MapColoring::parse(color)))
+}
+
+#[get("/map.svg?<color>")]
+fn get_town_svg(color: Option<&str>, data: &State<ViewerData>) -> (ContentType, String) {
+    let world = data.world.lock().expect("lock shared world");
+    (
+        ContentType::SVG,
+        render_map(world.data(), MapColoring::parse(color)),
+    )
+}
+
+#[get("/select/<id>")]
+fn select_town(id: usize, data: &State<ViewerData>) -> Redirect {
+    let mut world = data.world.lock().expect("lock shared world");
+
+    if id < world.towns().len() {
+        world.select(TownId::new(id));
+    }
+
+    Redirect::to(uri!("/town", get_town(Option::<&str>::None)))
+}
+
+#[derive(FromForm)]
+struct NameForm {
+    first: String,
+    last: String,
+}
+
+#[derive(FromForm)]
+struct CharacterForm {
+    other: usize,
+}
+
+#[derive(FromForm)]
+struct BuildingForm {
+    building: usize,
+}
+
+#[derive(FromForm)]
+struct OwnerForm {
+    owners: String,
+}
+
+#[derive(FromForm)]
+struct YearForm {
+    year: u32,
+}
+
+#[derive(FromForm)]
+struct SeedForm {
+    seed: u64,
+}
+
+#[derive(FromForm)]
+struct LawForm {
+    law: String,
+}
+
+/// An empty heir revokes the testament.
+#[derive(FromForm)]
+struct TestamentForm {
+    heir: String,
+}
+
+/// Changes the world, unless the simulation is running, & records the result in the history,
+/// so rewinding to the current year keeps the change.
+fn edit_world<T>(
+    data: &ViewerData,
+    edit: impl FnOnce(&mut World) -> EditResult<T>,
+) -> EditResult<T> {
+    if is_running(data) {
+        return Err("Stop the running simulation first!".to_string());
+    }
+
+    let mut world = data.world.lock().expect("lock shared world");
+    let result = edit(&mut world)?;
+    let mut history = data.history.lock().expect("lock shared history");
+    history.record(&world);
+    Ok(result)
+}
+
+/// Redirects to the page of the changed character or shows the error.
+fn show_edit_result(result: EditResult<()>, id: usize) -> Result<Redirect, RawHtml<String>> {
+    match result {
+        Ok(()) => Ok(Redirect::to(uri!("/character", get_character(id)))),
+        Err(error) => Err(RawHtml(visualize_error(
+            &error,
+            &format!("/character/{}", id),
+        ))),
+    }
+}
+
+#[post("/<id>/rename", data = "<form>")]
+fn rename_character(
+    id: usize,
+    form: Form<NameForm>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    let result = edit_world(data, |world| {
+        rename(world, CharacterId::new(id), &form.first, &form.last)
+    });
+    show_edit_result(result, id)
+}
+
+#[post("/<id>/kill")]
+fn kill_character(id: usize, data: &State<ViewerData>) -> Result<Redirect, RawHtml<String>> {
+    let result = edit_world(data, |world| kill(world, CharacterId::new(id)));
+    show_edit_result(result, id)
+}
+
+#[post("/<id>/marry", data = "<form>")]
+fn marry_character(
+    id: usize,
+    form: Form<CharacterForm>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    let result = edit_world(data, |world| {
+        arrange_marriage(world, CharacterId::new(id), CharacterId::new(form.other))
+    });
+    show_edit_result(result, id)
+}
+
+#[post("/<id>/child", data = "<form>")]
+fn create_child(
+    id: usize,
+    form: Form<CharacterForm>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    match edit_world(data, |world| {
+        have_child(world, CharacterId::new(id), CharacterId::new(form.other))
+    }) {
+        Ok(child) => Ok(Redirect::to(uri!("/character", get_character(child.id())))),
+        Err(error) => show_edit_result(Err(error), id),
+    }
+}
+
+#[post("/<id>/move", data = "<form>")]
+fn move_character(
+    id: usize,
+    form: Form<BuildingForm>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    let result = edit_world(data, |world| {
+        move_family(world, CharacterId::new(id), BuildingId::new(form.building))
+    });
+    show_edit_result(result, id)
+}
+
+#[post("/<id>/testament", data = "<form>")]
+fn change_testament(
+    id: usize,
+    form: Form<TestamentForm>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    let result = parse_character_ids(&form.heir).and_then(|heirs| match heirs[..] {
+        [] => Ok(None),
+        [heir] => Ok(Some(heir)),
+        _ => Err("A testament names only one heir!".to_string()),
+    });
+    let result = result.and_then(|heir| {
+        edit_world(data, |world| {
+            write_testament(world, CharacterId::new(id), heir)
+        })
+    });
+    show_edit_result(result, id)
+}
+
+#[post("/<id>/owner", data = "<form>")]
+fn transfer(
+    id: usize,
+    form: Form<OwnerForm>,
+    data: &State<ViewerData>,
+) -> Result<Redirect, RawHtml<String>> {
+    match parse_character_ids(&form.owners).and_then(|owners| {
+        edit_world(data, |world| {
+            transfer_building(world, BuildingId::new(id), owners)
+        })
+    }) {
+        Ok(()) => Ok(Redirect::to(uri!("/building", get_building(id)))),
+        Err(error) => Err(RawHtml(visualize_error(
+            &error,
+            &format!("/building/{}", id),
+        ))),
+    }
 }
 
 #[rocket::main]
 async fn main() {
-    let simulation_data = init_simulation(Date::new(1800), 100, 50);
+    let settings = TownSettings::default();
 
     let data = ViewerData {
-        data: Mutex::new(simulation_data),
+        world: Arc::new(Mutex::new(init_simulation(&settings))),
+        history: Arc::new(Mutex::new(History::default())),
+        progress: Arc::new(Mutex::new(Progress::default())),
     };
 
+    start_run(
+        &data,
+        settings.start_year + settings.warm_up_years,
+        Vec::new(),
+    );
+
     if let Err(e) = rocket::build()
         .manage(data)
         .mount("/static", FileServer::from("town_viewer/static/"))
-        .mount("/", routes![get_overview, simulate])
-        .mount("/building", routes![get_buildings, get_building])
-        .mount("/character", routes![get_characters, get_character])
-        .mount("/town", routes![get_town])
+        .mount("/", routes![get_overview, rewind, reroll, change_law])
+        .mount(
+            "/simulate",
+            routes![simulate, run_simulation, get_progress, stop_simulation],
+        )
+        .mount("/stats", routes![get_statistics, get_statistics_csv])
+        .mount("/new", routes![get_new_town, create_new_town])
+        .mount("/building", routes![get_buildings, get_building, transfer])
+        .mount(
+            "/character",
+            routes![
+                get_characters,
+                get_character,
+                get_biography_markdown,
+                rename_character,
+                kill_character,
+                marry_character,
+                create_child,
+                move_character,
+                change_testament,
+            ],
+        )
+        .mount("/family", routes![get_families, get_family])
+        .mount("/town", routes![get_town, get_town_svg, select_town])
         .launch()
         .await
     {
@@ -0,0 +1,117 @@
+use crate::ViewerData;
+use std::thread;
+use town_simulation::generation::number::RandomNumberGenerator;
+use town_simulation::simulation::pipeline::SimulationPipeline;
+use town_simulation::simulation::run::{check_conditions, StopCondition};
+use town_simulation::simulation::world::simulate_world_year;
+
+/// The progress of simulating several years in the background.
+#[derive(Clone, Debug, Default)]
+pub struct Progress {
+    pub start_year: u32,
+    pub target_year: u32,
+    pub current_year: u32,
+    pub is_running: bool,
+    pub should_stop: bool,
+    /// Why the last run ended.
+    pub result: Option<String>,
+}
+
+impl Progress {
+    pub fn get_simulated_years(&self) -> u32 {
+        self.current_year.saturating_sub(self.start_year)
+    }
+
+    pub fn get_total_years(&self) -> u32 {
+        self.target_year.saturating_sub(self.start_year)
+    }
+}
+
+pub fn is_running(data: &ViewerData) -> bool {
+    data.progress
+        .lock()
+        .expect("lock shared progress")
+        .is_running
+}
+
+/// Simulates until the start of the target year in a background thread.
+/// The world is only locked for one year at a time, so it can be viewed in between.
+/// Returns false, if another run is still active.
+pub fn start_run(data: &ViewerData, target_year: u32, conditions: Vec<StopCondition>) -> bool {
+    let world = data.world.clone();
+    let history = data.history.clone();
+    let progress = data.progress.clone();
+    let (start_year, seed) = {
+        let world = world.lock().expect("lock shared world");
+        (world.data().date.get_year(), *world.seed())
+    };
+
+    {
+        let mut progress = progress.lock().expect("lock shared progress");
+
+        if progress.is_running {
+            return false;
+        }
+
+        *progress = Progress {
+            start_year,
+            target_year,
+            current_year: start_year,
+            is_running: true,
+            should_stop: false,
+            result: None,
+        };
+    }
+
+    thread::spawn(move || {
+        let mut pipeline = SimulationPipeline::new(RandomNumberGenerator::Seeded(seed));
+
+        let result = loop {
+            let mut world = world.lock().expect("lock shared world");
+            let year = world.data().date.get_year();
+            let should_stop = {
+                let mut progress = progress.lock().expect("lock shared progress");
+                progress.current_year = year;
+                progress.should_stop
+            };
+
+            if should_stop {
+                break format!("Stopped by the user in {}.", year);
+            } else if year >= target_year {
+                break format!("Reached the year {}.", year);
+            } else if let Some(condition) = check_conditions(&world, &conditions) {
+                break format!(
+                    "Stopped in {}, because {}.",
+                    year,
+                    show_condition(condition)
+                );
+            }
+
+            history.lock().expect("lock shared history").record(&world);
+            simulate_world_year(&mut world, &mut pipeline);
+        };
+
+        let mut progress = progress.lock().expect("lock shared progress");
+        progress.is_running = false;
+        progress.result = Some(result);
+    });
+
+    true
+}
+
+/// Asks the active run to stop after the current year.
+pub fn stop_run(data: &ViewerData) {
+    data.progress
+        .lock()
+        .expect("lock shared progress")
+        .should_stop = true;
+}
+
+fn show_condition(condition: StopCondition) -> String {
+    match condition {
+        StopCondition::PopulationBelow(value) => {
+            format!("the population dropped below {}", value)
+        }
+        StopCondition::PopulationAbove(value) => format!("the population rose above {}", value),
+    }
+}
@@ -1,27 +1,161 @@
-use crate::visualize::character::show_character_id_link;
-use crate::visualize::html;
+use crate::visualize::character::{show_character_id_link, show_optional};
+use crate::visualize::{escape, get_page, get_text, html, show_pagination, show_select, to_query};
+use rocket::FromForm;
+use std::cmp::Reverse;
+use town_simulation::model::building::owner::Owner;
 use town_simulation::model::building::usage::{BuildingUsage, Home};
 use town_simulation::model::building::{Building, BuildingId, BuildingMgr};
 use town_simulation::model::character::{CharacterId, CharacterMgr};
 use town_simulation::model::time::Date;
+use town_simulation::usecase::building::address::get_address;
+use town_simulation::usecase::building::filter::{
+    filter_buildings, BuildingFilter, BuildingStatus,
+};
+use town_simulation::usecase::building::ownership::get_buildings_owned_by_town;
 use town_simulation::SimulationData;
 
-pub fn visualize_buildings(data: &SimulationData) -> String {
+#[derive(FromForm, Clone, Debug, Default)]
+pub struct BuildingQuery {
+    pub text: Option<String>,
+    pub usage: Option<String>,
+    pub status: Option<String>,
+    /// The town or the id of a character.
+    pub owner: Option<String>,
+    pub min_age: Option<u32>,
+    pub max_age: Option<u32>,
+    pub sort: Option<String>,
+    pub page: Option<usize>,
+}
+
+impl BuildingQuery {
+    pub fn get_filter(&self) -> BuildingFilter {
+        BuildingFilter {
+            text: get_text(&self.text),
+            usage: get_text(&self.usage),
+            status: match self.status.as_deref() {
+                Some("construction") => Some(BuildingStatus::UnderConstruction),
+                Some("standing") => Some(BuildingStatus::Standing),
+                Some("demolished") => Some(BuildingStatus::Demolished),
+                _ => None,
+            },
+            owner: get_text(&self.owner).and_then(|owner| {
+                if owner.eq_ignore_ascii_case("town") {
+                    Some(Owner::Town)
+                } else {
+                    owner
+                        .parse()
+                        .ok()
+                        .map(|id| Owner::Character(CharacterId::new(id)))
+                }
+            }),
+            min_age: self.min_age,
+            max_age: self.max_age,
+        }
+    }
+
+    /// Returns the query string without the page.
+    pub fn to_query(&self) -> String {
+        to_query(&[
+            ("text", self.text.clone()),
+            ("usage", self.usage.clone()),
+            ("status", self.status.clone()),
+            ("owner", self.owner.clone()),
+            ("min_age", self.min_age.map(|age| age.to_string())),
+            ("max_age", self.max_age.map(|age| age.to_string())),
+            ("sort", self.sort.clone()),
+        ])
+    }
+}
+
+pub fn visualize_buildings(data: &SimulationData, query: &BuildingQuery) -> String {
     let manager = &data.building_manager;
+    let mut buildings = filter_buildings(data, &query.get_filter());
+    let page = query.page.unwrap_or(1);
+
+    sort_buildings(&mut buildings, query.sort.as_deref(), data.date);
 
     html(format!(
         "
   <h1>Buildings</h1>
   <p><b>Total:</b> {}</p>
+  <p><b>Owned by Town:</b> {}</p>
+  {}
+  <p><b>Matching:</b> {}</p>
   <ul>
     {}
   </ul>
+  {}
   <p><a href=\"/\">Back</a></p>",
         manager.get_all().len(),
-        show_building_list(manager.get_all(), data.date),
+        get_buildings_owned_by_town(manager).len(),
+        show_search_form(query),
+        buildings.len(),
+        show_building_list(get_page(&buildings, page), data.date),
+        show_pagination("/building", &query.to_query(), page, buildings.len()),
     ))
 }
 
+fn sort_buildings(buildings: &mut [&Building], sort: Option<&str>, date: Date) {
+    match sort {
+        Some("newest") => buildings.sort_by_key(|b| (b.get_age(date), b.id().id())),
+        Some("oldest") => buildings.sort_by_key(|b| (Reverse(b.get_age(date)), b.id().id())),
+        Some("condition") => buildings.sort_by_key(|b| (*b.condition(), b.id().id())),
+        _ => buildings.sort_by_key(|b| b.id().id()),
+    }
+}
+
+fn show_search_form(query: &BuildingQuery) -> String {
+    let usages: Vec<String> = [
+        BuildingUsage::house(),
+        BuildingUsage::apartments(0),
+        BuildingUsage::MarketSquare,
+        BuildingUsage::Temple,
+    ]
+    .iter()
+    .map(|usage| usage.to_string())
+    .collect();
+    let mut usage_options = vec![("", "Any")];
+    usage_options.extend(usages.iter().map(|usage| (usage.as_str(), usage.as_str())));
+
+    format!(
+        "<form action=\"/building\">
+    <label>Search <input type=\"text\" name=\"text\" value=\"{}\"></label>
+    <label>Usage {}</label>
+    <label>Status {}</label>
+    <label>Owner <input type=\"text\" name=\"owner\" value=\"{}\" placeholder=\"town or id\"></label>
+    <label>Age <input type=\"number\" name=\"min_age\" min=\"0\" value=\"{}\"></label>
+    <label>to <input type=\"number\" name=\"max_age\" min=\"0\" value=\"{}\"></label>
+    <label>Sort by {}</label>
+    <input type=\"submit\" value=\"Search\">
+  </form>",
+        escape(query.text.as_deref().unwrap_or_default()),
+        show_select("usage", &usage_options, query.usage.as_deref()),
+        show_select(
+            "status",
+            &[
+                ("", "Any"),
+                ("construction", "Under Construction"),
+                ("standing", "Standing"),
+                ("demolished", "Demolished")
+            ],
+            query.status.as_deref(),
+        ),
+        escape(query.owner.as_deref().unwrap_or_default()),
+        show_optional(query.min_age),
+        show_optional(query.max_age),
+        show_select(
+            "sort",
+            &[
+                ("id", "Id"),
+                ("newest", "Newest"),
+                ("oldest", "Oldest"),
+                ("condition", "Condition")
+            ],
+            query.sort.as_deref(),
+        ),
+    )
+}
+
 pub fn visualize_building(data: &SimulationData, id: usize) -> String {
     let manager = &data.building_manager;
     let building_id = BuildingId::new(id);
@@ -32,19 +166,33 @@ pub fn visualize_building(data: &SimulationData, id: usize) -> String {
   <h1>Building {0}</h1>
   <h2>General</h2>
   <p><b>Id:</b> {0}</p>
-  <p><b>Construction Date:</b> {1}</p>
-  <p><b>Age:</b> {2}</p>
-  <p><b>Builder:</b> {3}</p>
-  <p><b>Owner:</b> {4}</p>{5}
-  {6}
+  <p><b>Address:</b> {11}</p>{12}
+  <p><b>Construction Date:</b> {1}</p>{10}{2}
+  <p><b>Age:</b> {3}</p>
+  <p><b>Condition:</b> {4}</p>{5}
+  <p><b>Builder:</b> {6}</p>
+  <p><b>Owner:</b> {7}</p>{8}
+  {9}
+  <h2>Edit</h2>
+  <form action=\"/building/{0}/owner\" method=\"post\">
+    <label>Owner Ids <input type=\"text\" name=\"owners\" value=\"{13}\"> (empty for the town)</label>
+    <input type=\"submit\" value=\"Transfer\">
+  </form>
   <a href=\"/building\">Back</a>",
             id,
             building.construction_date().get_year(),
+            show_demolition(building),
             building.get_age(data.date),
+            building.condition(),
+            show_renovations(building),
             show_character_id_link(&data.character_manager, *building.builder()),
-            show_character_id_link(&data.character_manager, *building.owner()),
+            show_owner(&data.character_manager, building.owner()),
             show_previous_owners(&data.character_manager, building.previous_owners()),
             show_usage(&data.character_manager, building.usage()),
+            show_construction(&data.character_manager, building),
+            show_address(data, building_id),
+            show_district(data, building_id),
+            show_owner_ids(building.owner()),
         ))
     } else {
         html(format!(
@@ -56,7 +204,76 @@ pub fn visualize_building(data: &SimulationData, id: usize) -> String {
     }
 }
 
-fn show_building_list(building: &[Building], date: Date) -> String {
+pub fn show_address(data: &SimulationData, id: BuildingId) -> String {
+    get_address(data, id)
+        .map(|address| escape(&address.to_string()))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn show_owner_ids(owner: &Owner) -> String {
+    let ids: Vec<String> = owner
+        .get_characters()
+        .iter()
+        .map(|id| id.id().to_string())
+        .collect();
+
+    ids.join(", ")
+}
+
+fn show_district(data: &SimulationData, id: BuildingId) -> String {
+    if let Some((block, _)) = data.map.find_building(id) {
+        format!(
+            "\n  <p><b>District:</b> {:?}</p>\n  <p><b>Land Value:</b> {}</p>",
+            data.map.get_district(block),
+            data.map.get_land_value(block)
+        )
+    } else {
+        "".to_string()
+    }
+}
+
+fn show_construction(manager: &CharacterMgr, building: &Building) -> String {
+    if building.is_under_construction() {
+        let vector: Vec<String> = building
+            .workers()
+            .iter()
+            .map(|id| show_occupant(manager, *id))
+            .collect();
+
+        format!(
+            "\n  <p><b>Under Construction</b></p>\n  <p><b>Workers:</b></p>\n  <ul>{}</ul>",
+            vector.join("\n")
+        )
+    } else if let Some(date) = building.completion_date() {
+        format!("\n  <p><b>Completion Date:</b> {}</p>", date.get_year())
+    } else {
+        "".to_string()
+    }
+}
+
+fn show_demolition(building: &Building) -> String {
+    if let Some(date) = building.demolition_date() {
+        format!("\n  <p><b>Demolition Date:</b> {}</p>", date.get_year())
+    } else {
+        "".to_string()
+    }
+}
+
+fn show_renovations(building: &Building) -> String {
+    if building.renovation_dates().is_empty() {
+        "".to_string()
+    } else {
+        let vector: Vec<String> = building
+            .renovation_dates()
+            .iter()
+            .map(|date| date.get_year().to_string())
+            .collect();
+
+        format!("\n  <p><b>Renovations:</b> {}</p>", vector.join(", "))
+    }
+}
+
+fn show_building_list(building: &[&Building], date: Date) -> String {
     let vector: Vec<String> = building
         .iter()
         .map(|b| show_building_in_list(b, date))
@@ -78,20 +295,49 @@ pub fn show_building_id_link(manager: &BuildingMgr, id: BuildingId) -> String {
 }
 
 pub fn show_building_link(building: &Building) -> String {
+    let name = format!("{} {}", building.usage(), building.id().id());
+
     format!(
-        "<a href=\"/building/{0}\">{1} {0}</a>",
+        "<a href=\"/building/{}\">{}</a>",
         building.id().id(),
-        building.usage()
+        if building.is_demolished() {
+            format!("<del>{}</del>", name)
+        } else {
+            name
+        }
     )
 }
 
-fn show_previous_owners(manager: &CharacterMgr, previous_owners: &[CharacterId]) -> String {
+pub fn show_owner(manager: &CharacterMgr, owner: &Owner) -> String {
+    match owner {
+        Owner::Character(id) => show_character_id_link(manager, *id),
+        Owner::CoOwners(shares) => {
+            let vector: Vec<String> = shares
+                .iter()
+                .map(|(id, _)| {
+                    let (share, total) = owner.get_share(*id);
+                    format!(
+                        "{} ({}/{})",
+                        show_character_id_link(manager, *id),
+                        share,
+                        total
+                    )
+                })
+                .collect();
+
+            vector.join(", ")
+        }
+        Owner::Town => "Town".to_string(),
+    }
+}
+
+fn show_previous_owners(manager: &CharacterMgr, previous_owners: &[Owner]) -> String {
     if previous_owners.is_empty() {
         "".to_string()
     } else {
         let vector: Vec<String> = previous_owners
             .iter()
-            .map(|id| show_occupant(manager, *id))
+            .map(|owner| format!("<li>{}</li>", show_owner(manager, owner)))
             .collect();
 
         format!(
@@ -108,6 +354,7 @@ fn show_usage(manager: &CharacterMgr, usage: &BuildingUsage) -> String {
         match usage {
             BuildingUsage::Apartments(homes) => show_homes(manager, homes),
             BuildingUsage::House(home) => show_occupants(manager, home),
+            BuildingUsage::MarketSquare | BuildingUsage::Temple => "".to_string(),
         },
     )
 }
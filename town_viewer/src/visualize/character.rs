@@ -1,6 +1,14 @@
-use crate::visualize::building::{show_building_id_link, show_building_link};
-use crate::visualize::html;
+use crate::visualize::building::{show_address, show_building_id_link, show_building_link};
+use crate::visualize::family::show_family_link;
+use crate::visualize::{
+    escape, get_page, get_text, html, show_pagination, show_select, show_town_link, to_query,
+};
+use rocket::FromForm;
+use std::cmp::Reverse;
+use town_simulation::biography::get_biography;
+use town_simulation::model::building::BuildingId;
 use town_simulation::model::building::BuildingMgr;
+use town_simulation::model::character::gender::Gender;
 use town_simulation::model::character::relation::building::BuildingRelationType::{
     Builder, ExOwner, Owner,
 };
@@ -11,34 +19,179 @@ use town_simulation::model::character::relation::character::CharacterRelation;
 use town_simulation::model::character::{Character, CharacterId, CharacterMgr};
 use town_simulation::model::time::Date;
 use town_simulation::usecase::building::occupancy::get_building_occupied_by;
+use town_simulation::usecase::character::filter::{
+    filter_characters, CharacterFilter, CharacterStatus,
+};
 use town_simulation::usecase::character::relation::get::{
-    get_relation_to_in_laws, get_relation_to_relatives, get_spouses,
+    get_relation_to_guardianship, get_relation_to_in_laws, get_relation_to_relatives, get_spouses,
 };
+use town_simulation::world::World;
 use town_simulation::SimulationData;
 
-pub fn visualize_characters(data: &SimulationData) -> String {
+#[derive(FromForm, Clone, Debug, Default)]
+pub struct CharacterQuery {
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub gender: Option<String>,
+    pub min_age: Option<u32>,
+    pub max_age: Option<u32>,
+    pub family: Option<String>,
+    pub building: Option<usize>,
+    pub married: Option<String>,
+    pub sort: Option<String>,
+    pub page: Option<usize>,
+}
+
+impl CharacterQuery {
+    pub fn get_filter(&self) -> CharacterFilter {
+        CharacterFilter {
+            name: get_text(&self.name),
+            status: match self.status.as_deref() {
+                Some("alive") => Some(CharacterStatus::Alive),
+                Some("emigrated") => Some(CharacterStatus::Emigrated),
+                Some("dead") => Some(CharacterStatus::Dead),
+                _ => None,
+            },
+            gender: match self.gender.as_deref() {
+                Some("male") => Some(Gender::Male),
+                Some("female") => Some(Gender::Female),
+                _ => None,
+            },
+            min_age: self.min_age,
+            max_age: self.max_age,
+            family: get_text(&self.family),
+            building: self.building.map(BuildingId::new),
+            married: match self.married.as_deref() {
+                Some("yes") => Some(true),
+                Some("no") => Some(false),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the query string without the page.
+    pub fn to_query(&self) -> String {
+        to_query(&[
+            ("name", self.name.clone()),
+            ("status", self.status.clone()),
+            ("gender", self.gender.clone()),
+            ("min_age", self.min_age.map(|age| age.to_string())),
+            ("max_age", self.max_age.map(|age| age.to_string())),
+            ("family", self.family.clone()),
+            ("building", self.building.map(|id| id.to_string())),
+            ("married", self.married.clone()),
+            ("sort", self.sort.clone()),
+        ])
+    }
+}
+
+pub fn visualize_characters(data: &SimulationData, query: &CharacterQuery) -> String {
     let manager = &data.character_manager;
     let total = manager.get_all().len();
     let alive = manager.get_all().iter().filter(|&c| c.is_alive()).count();
     let dead = total - alive;
+    let emigrated = manager
+        .get_all()
+        .iter()
+        .filter(|&c| c.is_alive() && c.has_emigrated())
+        .count();
+    let mut characters = filter_characters(data, &query.get_filter());
+    let page = query.page.unwrap_or(1);
+
+    sort_characters(&mut characters, query.sort.as_deref(), data.date);
+
     html(format!(
         "
   <h1>Characters</h1>
   <p><b>Alive:</b> {}</p>
+  <p><b>Emigrated:</b> {}</p>
   <p><b>Dead:</b> {}</p>
   <p><b>Total:</b> {}</p>
+  {}
+  <p><b>Matching:</b> {}</p>
   <ul>
     {}
   </ul>
+  {}
   <p><a href=\"/\">Back</a></p>",
         alive,
+        emigrated,
         dead,
         total,
-        show_character_list(manager.get_all(), data.date),
+        show_search_form(query),
+        characters.len(),
+        show_character_list(get_page(&characters, page), data.date),
+        show_pagination("/character", &query.to_query(), page, characters.len()),
     ))
 }
 
-pub fn visualize_character(data: &SimulationData, id: usize) -> String {
+fn sort_characters(characters: &mut [&Character], sort: Option<&str>, date: Date) {
+    match sort {
+        Some("name") => characters.sort_by_key(|c| (c.name().sorted(), c.id().id())),
+        Some("youngest") => characters.sort_by_key(|c| (c.get_age(date), c.id().id())),
+        Some("oldest") => characters.sort_by_key(|c| (Reverse(c.get_age(date)), c.id().id())),
+        _ => characters.sort_by_key(|c| c.id().id()),
+    }
+}
+
+fn show_search_form(query: &CharacterQuery) -> String {
+    format!(
+        "<form action=\"/character\">
+    <label>Name <input type=\"text\" name=\"name\" value=\"{}\"></label>
+    <label>Family <input type=\"text\" name=\"family\" value=\"{}\"></label>
+    <label>Status {}</label>
+    <label>Gender {}</label>
+    <label>Married {}</label>
+    <label>Age <input type=\"number\" name=\"min_age\" min=\"0\" value=\"{}\"></label>
+    <label>to <input type=\"number\" name=\"max_age\" min=\"0\" value=\"{}\"></label>
+    <label>Occupies Building <input type=\"number\" name=\"building\" min=\"0\" value=\"{}\"></label>
+    <label>Sort by {}</label>
+    <input type=\"submit\" value=\"Search\">
+  </form>",
+        escape(query.name.as_deref().unwrap_or_default()),
+        escape(query.family.as_deref().unwrap_or_default()),
+        show_select(
+            "status",
+            &[
+                ("", "Any"),
+                ("alive", "Alive"),
+                ("emigrated", "Emigrated"),
+                ("dead", "Dead")
+            ],
+            query.status.as_deref(),
+        ),
+        show_select(
+            "gender",
+            &[("", "Any"), ("male", "Male"), ("female", "Female")],
+            query.gender.as_deref(),
+        ),
+        show_select(
+            "married",
+            &[("", "Any"), ("yes", "Yes"), ("no", "No")],
+            query.married.as_deref(),
+        ),
+        show_optional(query.min_age),
+        show_optional(query.max_age),
+        show_optional(query.building),
+        show_select(
+            "sort",
+            &[
+                ("id", "Id"),
+                ("name", "Name"),
+                ("youngest", "Youngest"),
+                ("oldest", "Oldest")
+            ],
+            query.sort.as_deref(),
+        ),
+    )
+}
+
+pub fn show_optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+pub fn visualize_character(world: &World, id: usize) -> String {
+    let data = world.data();
     let manager = &data.character_manager;
     let character_id = CharacterId::new(id);
 
@@ -48,25 +201,35 @@ pub fn visualize_character(data: &SimulationData, id: usize) -> String {
   <h1>{}</h1>
   <h2>General</h2>
   <p><b>Id:</b> {}</p>
-  <p><b>Gender:</b> {:?}</p>
-  <p><b>Birth Date:</b> {}</p>{}
+  <p><b>Gender:</b> {:?}</p>{}
+  <p><b>Birth Date:</b> {}</p>{}{}
   <p><b>Age:</b> {}</p>
-  <h2>Characters</h2>{}{}{}
-  <h2>Buildings</h2>{}{}{}{}
+  <p><b>Town:</b> {}</p>
+  <h2>Characters</h2>{}{}{}{}{}
+  <h2>Buildings</h2>{}
+  <h2>Biography</h2>
+  <p>{}</p>
+  <p><a href=\"/character/{}/biography.md\" download=\"biography.md\">Export Markdown</a></p>
+  <h2>Edit</h2>{}
   <a href=\"/character\">Back</a>",
-            character.name(),
+            escape(&character.name().to_string()),
             id,
             character.gender(),
+            show_family(character),
             character.birth_date().get_year(),
             show_death(character),
+            show_emigration(character),
             character.get_age(data.date),
+            show_town_link(world, *character.town()),
             show_spouse(manager, character_id),
             show_relatives(manager, character_id),
             show_in_laws(manager, character_id),
-            show_home(data, character_id),
-            show_build_buildings(&data.building_manager, character.building_relations()),
-            show_owned_buildings(&data.building_manager, character.building_relations()),
-            show_ex_owned_buildings(&data.building_manager, character.building_relations()),
+            show_guardianship(manager, character_id),
+            show_testament(manager, character),
+            show_buildings(data, character),
+            escape(&get_biography(world, character_id)),
+            id,
+            show_edit_forms(character),
         ))
     } else {
         html(format!(
@@ -78,7 +241,7 @@ pub fn visualize_character(data: &SimulationData, id: usize) -> String {
     }
 }
 
-fn show_character_list(characters: &[Character], date: Date) -> String {
+fn show_character_list(characters: &[&Character], date: Date) -> String {
     let vector: Vec<String> = characters
         .iter()
         .map(|c| show_character_in_list(c, date))
@@ -108,10 +271,68 @@ pub fn show_character_link(character: &Character) -> String {
 }
 
 fn show_character_name(character: &Character) -> String {
+    let name = escape(&character.name().to_string());
+
     if character.is_alive() {
-        character.name().to_string()
+        name
     } else {
-        format!("<del>{}</del>", character.name())
+        format!("<del>{}</del>", name)
+    }
+}
+
+fn show_edit_forms(character: &Character) -> String {
+    let id = character.id().id();
+    let name = character.name();
+
+    format!(
+        "
+  <form action=\"/character/{0}/rename\" method=\"post\">
+    <label>First Name <input type=\"text\" name=\"first\" value=\"{1}\" required></label>
+    <label>Last Name <input type=\"text\" name=\"last\" value=\"{2}\"></label>
+    <input type=\"submit\" value=\"Rename\">
+  </form>
+  <form action=\"/character/{0}/marry\" method=\"post\">
+    <label>Spouse Id <input type=\"number\" name=\"other\" min=\"0\" required></label>
+    <input type=\"submit\" value=\"Arrange Marriage\">
+  </form>
+  <form action=\"/character/{0}/child\" method=\"post\">
+    <label>Spouse Id <input type=\"number\" name=\"other\" min=\"0\" required></label>
+    <input type=\"submit\" value=\"Have a Child\">
+  </form>
+  <form action=\"/character/{0}/move\" method=\"post\">
+    <label>Building Id <input type=\"number\" name=\"building\" min=\"0\" required></label>
+    <input type=\"submit\" value=\"Move Family\">
+  </form>
+  <form action=\"/character/{0}/testament\" method=\"post\">
+    <label>Heir Id <input type=\"number\" name=\"heir\" min=\"0\" value=\"{3}\"></label>
+    <input type=\"submit\" value=\"Write Testament\">
+  </form>
+  <form action=\"/character/{0}/kill\" method=\"post\">
+    <input type=\"submit\" value=\"Kill\">
+  </form>",
+        id,
+        escape(name.get_first()),
+        escape(name.get_last().unwrap_or_default()),
+        show_optional(character.testament().map(|heir| heir.id())),
+    )
+}
+
+fn show_family(character: &Character) -> String {
+    character
+        .name()
+        .get_last()
+        .map(|name| format!("\n  <p><b>Family:</b> {}</p>", show_family_link(name)))
+        .unwrap_or_default()
+}
+
+fn show_testament(manager: &CharacterMgr, character: &Character) -> String {
+    if let Some(heir) = character.testament() {
+        format!(
+            "\n<p><b>Testament:</b> {}</p>",
+            show_character_id_link(manager, *heir)
+        )
+    } else {
+        "".to_string()
     }
 }
 
@@ -123,11 +344,18 @@ fn show_death(character: &Character) -> String {
     }
 }
 
+fn show_emigration(character: &Character) -> String {
+    if let Some(date) = character.emigration_date() {
+        format!("\n<p><b>Emigration Date:</b> {}</p>", date.get_year())
+    } else {
+        "".to_string()
+    }
+}
+
 fn show_spouse(manager: &CharacterMgr, character: CharacterId) -> String {
     if let Some(spouse) = get_spouses(manager, character)
         .iter()
-        .map(|id| manager.get(*id))
-        .flatten()
+        .filter_map(|id| manager.get(*id))
         .next()
     {
         format!(
@@ -148,6 +376,14 @@ fn show_in_laws(manager: &CharacterMgr, id: CharacterId) -> String {
     show_relations(manager, get_relation_to_in_laws(manager, id), "In-Laws")
 }
 
+fn show_guardianship(manager: &CharacterMgr, id: CharacterId) -> String {
+    show_relations(
+        manager,
+        get_relation_to_guardianship(manager, id),
+        "Guardianship",
+    )
+}
+
 fn show_relations(
     manager: &CharacterMgr,
     mut relations: Vec<&CharacterRelation>,
@@ -178,11 +414,29 @@ fn show_relation(manager: &CharacterMgr, relation: &CharacterRelation) -> String
     )
 }
 
+/// The buildings can only be shown, if the town of the character is selected.
+fn show_buildings(data: &SimulationData, character: &Character) -> String {
+    if *character.town() != data.town {
+        return "\n  <p>Select the town of the character to see its buildings.</p>".to_string();
+    }
+
+    let relations = character.building_relations();
+
+    format!(
+        "{}{}{}{}",
+        show_home(data, *character.id()),
+        show_build_buildings(&data.building_manager, relations),
+        show_owned_buildings(&data.building_manager, relations),
+        show_ex_owned_buildings(&data.building_manager, relations),
+    )
+}
+
 fn show_home(data: &SimulationData, id: CharacterId) -> String {
     if let Some(building_id) = get_building_occupied_by(&data.character_manager, id) {
         format!(
-            "\n<p><b>Home:</b> {}</p>",
+            "\n<p><b>Home:</b> {} ({})</p>",
             show_building_id_link(&data.building_manager, building_id),
+            show_address(data, building_id),
         )
     } else {
         "".to_string()
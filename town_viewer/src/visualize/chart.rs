@@ -0,0 +1,178 @@
+use town_simulation::statistics::AgeGroup;
+
+const WIDTH: usize = 600;
+const HEIGHT: usize = 200;
+const MARGIN: usize = 40;
+const BAR_HEIGHT: usize = 12;
+const MALE_COLOR: &str = "#4682b4";
+const FEMALE_COLOR: &str = "#cd5c5c";
+
+pub struct Series<'a> {
+    pub name: &'a str,
+    pub color: &'a str,
+    pub values: Vec<usize>,
+}
+
+fn render_svg(width: usize, height: usize, elements: Vec<String>) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">
+ <rect width=\"{0}\" height=\"{1}\" fill=\"white\" stroke=\"black\"/>
+{2}
+</svg>",
+        width,
+        height,
+        elements.join("\n"),
+    )
+}
+
+fn render_text(x: usize, y: usize, anchor: &str, text: &str) -> String {
+    format!(
+        " <text x=\"{}\" y=\"{}\" font-size=\"10\" text-anchor=\"{}\" dominant-baseline=\"middle\">{}</text>",
+        x, y, anchor, text
+    )
+}
+
+/// Renders the series over the years with the first & last year, the maximum & a legend.
+pub fn render_line_chart(years: &[u32], series: &[Series]) -> String {
+    let max = series
+        .iter()
+        .flat_map(|series| series.values.iter().copied())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let steps = years.len().saturating_sub(1).max(1);
+    let inner_width = WIDTH - 2 * MARGIN;
+    let inner_height = HEIGHT - 2 * MARGIN;
+    let mut elements = vec![
+        render_text(MARGIN - 5, MARGIN, "end", &max.to_string()),
+        render_text(MARGIN - 5, HEIGHT - MARGIN, "end", "0"),
+    ];
+
+    if let (Some(first), Some(last)) = (years.first(), years.last()) {
+        elements.push(render_text(
+            MARGIN,
+            HEIGHT - MARGIN / 2,
+            "start",
+            &first.to_string(),
+        ));
+        elements.push(render_text(
+            WIDTH - MARGIN,
+            HEIGHT - MARGIN / 2,
+            "end",
+            &last.to_string(),
+        ));
+    }
+
+    for (index, series) in series.iter().enumerate() {
+        let points: Vec<String> = series
+            .values
+            .iter()
+            .enumerate()
+            .map(|(step, value)| {
+                format!(
+                    "{},{}",
+                    MARGIN + step * inner_width / steps,
+                    HEIGHT - MARGIN - value * inner_height / max
+                )
+            })
+            .collect();
+
+        elements.push(format!(
+            " <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"><title>{}</title></polyline>",
+            points.join(" "),
+            series.color,
+            series.name
+        ));
+        elements.push(format!(
+            " <text x=\"{}\" y=\"{}\" font-size=\"10\" fill=\"{}\">{}</text>",
+            MARGIN + index * 100,
+            MARGIN / 2,
+            series.color,
+            series.name
+        ));
+    }
+
+    render_svg(WIDTH, HEIGHT, elements)
+}
+
+pub fn render_bar_chart(bars: &[(String, usize)], color: &str) -> String {
+    let max = bars
+        .iter()
+        .map(|(_, value)| *value)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let height = 2 * MARGIN + bars.len() * (BAR_HEIGHT + 4);
+    let inner_width = WIDTH - 3 * MARGIN;
+    let mut elements = Vec::new();
+
+    for (index, (label, value)) in bars.iter().enumerate() {
+        let y = MARGIN + index * (BAR_HEIGHT + 4);
+        let width = value * inner_width / max;
+
+        elements.push(render_text(MARGIN - 5, y + BAR_HEIGHT / 2, "end", label));
+        elements.push(format!(
+            " <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            MARGIN, y, width, BAR_HEIGHT, color
+        ));
+        elements.push(render_text(
+            MARGIN + width + 5,
+            y + BAR_HEIGHT / 2,
+            "start",
+            &value.to_string(),
+        ));
+    }
+
+    render_svg(WIDTH, height, elements)
+}
+
+/// Renders the male residents of each age group to the left & the female ones to the right.
+/// The youngest group is at the bottom.
+pub fn render_pyramid(groups: &[AgeGroup]) -> String {
+    let max = groups
+        .iter()
+        .map(|group| *group.male().max(group.female()))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let height = 2 * MARGIN + groups.len() * (BAR_HEIGHT + 2);
+    let centre = WIDTH / 2;
+    let half_width = centre - 2 * MARGIN;
+    let mut elements = vec![
+        render_text(centre - 5, MARGIN / 2, "end", "Male"),
+        render_text(centre + 5, MARGIN / 2, "start", "Female"),
+    ];
+
+    for (index, group) in groups.iter().rev().enumerate() {
+        let y = MARGIN + index * (BAR_HEIGHT + 2);
+        let male = group.male() * half_width / max;
+        let female = group.female() * half_width / max;
+
+        elements.push(format!(
+            " <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"><title>{}</title></rect>",
+            centre - male,
+            y,
+            male,
+            BAR_HEIGHT,
+            MALE_COLOR,
+            group.male()
+        ));
+        elements.push(format!(
+            " <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"><title>{}</title></rect>",
+            centre,
+            y,
+            female,
+            BAR_HEIGHT,
+            FEMALE_COLOR,
+            group.female()
+        ));
+        elements.push(render_text(
+            MARGIN,
+            y + BAR_HEIGHT / 2,
+            "start",
+            &group.min_age().to_string(),
+        ));
+    }
+
+    render_svg(WIDTH, height, elements)
+}
@@ -0,0 +1,168 @@
+use crate::visualize::building::show_building_link;
+use crate::visualize::character::show_character_id_link;
+use crate::visualize::{escape, html, show_select};
+use rocket::http::RawStr;
+use town_simulation::family::{get_families, get_family, get_timeline, Family, FamilyEvent};
+use town_simulation::model::building::BuildingId;
+use town_simulation::model::character::{CharacterId, CharacterMgr};
+use town_simulation::model::town::TownId;
+use town_simulation::world::World;
+
+pub fn visualize_families(world: &World, sort: Option<&str>) -> String {
+    let mut families = get_families(world);
+
+    if sort == Some("wealth") {
+        families.sort_by(|a, b| b.wealth().cmp(a.wealth()));
+    }
+
+    let vector: Vec<String> = families
+        .iter()
+        .map(|family| {
+            format!(
+                "   <li>{} (Living: {}, Total: {}, Wealth: {})</li>",
+                show_family_link(family.name()),
+                family.living_members(),
+                family.members().len(),
+                family.wealth(),
+            )
+        })
+        .collect();
+
+    html(format!(
+        "
+  <h1>Families</h1>
+  <form action=\"/family\">
+    <label>Sort by {}</label>
+    <input type=\"submit\" value=\"Sort\">
+  </form>
+  <p><b>Families:</b> {}</p>
+  <ol>
+{}
+  </ol>
+  <a href=\"/\">Back</a>",
+        show_select("sort", &[("size", "Size"), ("wealth", "Wealth")], sort),
+        families.len(),
+        vector.join("\n"),
+    ))
+}
+
+pub fn visualize_family(world: &World, name: &str) -> String {
+    if let Some(family) = get_family(world, name) {
+        let manager = &world.data().character_manager;
+
+        html(format!(
+            "
+  <h1>Family {}</h1>
+  <h2>General</h2>
+  <p><b>Founder:</b> {}</p>
+  <p><b>Head:</b> {}</p>
+  <p><b>Living Members:</b> {}</p>
+  <p><b>Wealth:</b> {}</p>
+  <h2>Members</h2>
+  <ul>
+{}
+  </ul>
+  <h2>Property</h2>
+  <ul>
+{}
+  </ul>
+  <h2>Timeline</h2>
+  <ul>
+{}
+  </ul>
+  <a href=\"/family\">Back</a>",
+            escape(family.name()),
+            show_optional_link(manager, *family.founder()),
+            show_optional_link(manager, *family.head()),
+            family.living_members(),
+            family.wealth(),
+            show_members(manager, &family),
+            show_property(world, &family),
+            show_timeline(world, &family),
+        ))
+    } else {
+        html(format!(
+            "
+  <h1>Unknown Family {}!</h1>
+  <a href=\"/family\">Back</a>",
+            escape(name),
+        ))
+    }
+}
+
+pub fn show_family_link(name: &str) -> String {
+    format!(
+        "<a href=\"/family/{}\">{}</a>",
+        RawStr::new(name).percent_encode(),
+        escape(name),
+    )
+}
+
+fn show_optional_link(manager: &CharacterMgr, id: Option<CharacterId>) -> String {
+    id.map(|id| show_character_id_link(manager, id))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn show_members(manager: &CharacterMgr, family: &Family) -> String {
+    let vector: Vec<String> = family
+        .members()
+        .iter()
+        .map(|id| format!("   <li>{}</li>", show_character_id_link(manager, *id)))
+        .collect();
+
+    vector.join("\n")
+}
+
+fn show_property(world: &World, family: &Family) -> String {
+    let vector: Vec<String> = family
+        .property()
+        .iter()
+        .map(|(town, building)| format!("   <li>{}</li>", show_building(world, *town, *building)))
+        .collect();
+
+    vector.join("\n")
+}
+
+/// Only buildings of the selected town can be linked.
+fn show_building(world: &World, town: TownId, id: BuildingId) -> String {
+    let building = world.get_building_manager(town).get(id).unwrap();
+
+    if town == world.get_selected_town() {
+        show_building_link(building)
+    } else {
+        format!(
+            "{} {} in {}",
+            building.usage(),
+            id.id(),
+            escape(world.get_name(town))
+        )
+    }
+}
+
+fn show_timeline(world: &World, family: &Family) -> String {
+    let manager = &world.data().character_manager;
+    let vector: Vec<String> = get_timeline(world, family)
+        .into_iter()
+        .map(|(year, event)| {
+            let text = match event {
+                FamilyEvent::Birth(id) => {
+                    format!("{} is born", show_character_id_link(manager, id))
+                }
+                FamilyEvent::Death(id) => format!("{} dies", show_character_id_link(manager, id)),
+                FamilyEvent::Build {
+                    builder,
+                    town,
+                    building,
+                } => format!(
+                    "{} builds {}",
+                    show_character_id_link(manager, builder),
+                    show_building(world, town, building)
+                ),
+            };
+
+            format!("   <li><b>{}:</b> {}</li>", year, text)
+        })
+        .collect();
+
+    vector.join("\n")
+}
@@ -1,7 +1,16 @@
-use town_simulation::SimulationData;
+use crate::run::Progress;
+use rocket::http::RawStr;
+use town_simulation::model::inheritance::InheritanceLaw;
+use town_simulation::model::town::TownId;
+use town_simulation::world::World;
 
 pub mod building;
 pub mod character;
+pub mod chart;
+pub mod family;
+pub mod new_town;
+pub mod statistics;
+pub mod svg;
 pub mod town;
 
 pub fn html(body: String) -> String {
@@ -20,19 +29,268 @@ pub fn html(body: String) -> String {
     )
 }
 
-pub fn visualize_overview(data: &SimulationData) -> String {
+pub const PAGE_SIZE: usize = 50;
+
+/// Limits the page, which starts at 1, to the existing pages.
+fn clamp_page(page: usize, total: usize) -> (usize, usize) {
+    let pages = total.div_ceil(PAGE_SIZE).max(1);
+
+    (page.clamp(1, pages), pages)
+}
+
+/// Returns the items of the page or the last page.
+pub fn get_page<T>(items: &[T], page: usize) -> &[T] {
+    let (page, _) = clamp_page(page, items.len());
+    let start = (page - 1) * PAGE_SIZE;
+    let end = (start + PAGE_SIZE).min(items.len());
+
+    &items[start..end]
+}
+
+/// Links to the previous & next page, which keep the other parameters of the query.
+pub fn show_pagination(path: &str, query: &str, page: usize, total: usize) -> String {
+    let (page, pages) = clamp_page(page, total);
+    let link = |page: usize, text: &str| {
+        format!(
+            "<a href=\"{}?{}page={}\">{}</a>",
+            path,
+            if query.is_empty() {
+                String::new()
+            } else {
+                format!("{}&", query)
+            },
+            page,
+            text
+        )
+    };
+
+    format!(
+        "<p>{} Page {} of {} {}</p>",
+        if page > 1 {
+            link(page - 1, "Previous")
+        } else {
+            String::new()
+        },
+        page,
+        pages,
+        if page < pages {
+            link(page + 1, "Next")
+        } else {
+            String::new()
+        },
+    )
+}
+
+/// Returns the parameters with values as query string.
+pub fn to_query(parameters: &[(&str, Option<String>)]) -> String {
+    let vector: Vec<String> = parameters
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .as_ref()
+                .filter(|value| !value.is_empty())
+                .map(|value| format!("{}={}", name, RawStr::new(value).percent_encode()))
+        })
+        .collect();
+
+    vector.join("&")
+}
+
+/// Returns a select element, which keeps the selected option.
+pub fn show_select(name: &str, options: &[(&str, &str)], selected: Option<&str>) -> String {
+    let vector: Vec<String> = options
+        .iter()
+        .map(|(value, text)| {
+            format!(
+                "<option value=\"{}\"{}>{}</option>",
+                escape(value),
+                if Some(*value) == selected {
+                    " selected"
+                } else {
+                    ""
+                },
+                text
+            )
+        })
+        .collect();
+
+    format!("<select name=\"{}\">{}</select>", name, vector.join(""))
+}
+
+pub fn get_text(text: &Option<String>) -> Option<String> {
+    text.as_ref()
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn visualize_overview(world: &World, history: &[u32], progress: &Progress) -> String {
+    let data = world.data();
+
     html(format!(
         "
   <h1>Town Simulation</h1>
   <h2>Overview</h2>
   <p><b>Year:</b> {}</p>
+  <form action=\"/law\" method=\"post\">
+    <label><b>Inheritance Law:</b> {}</label>
+    <input type=\"submit\" value=\"Change\">
+  </form>
+  <p><b>Town:</b> {}</p>
   <p><b>Buildings</b>: <a href=\"/building\">{}</a></p>
   <p><b>Characters</b>: <a href=\"/character\">{}</a></p>
   <p><a href=\"/town\">Town Map</a></p>
+  <p><a href=\"/family\">Families</a></p>
+  <p><a href=\"/stats\">Statistics</a></p>
+  <h2>Towns</h2>
+  <ul>
+    {}
+  </ul>
   <h2>Actions</h2>
-  <p><a href=\"/simulate\">Simulate</a></p>",
+  {}
+  <p><a href=\"/new\">New Town</a></p>
+  <p><a href=\"/simulate\">Simulate</a></p>
+  <form action=\"/simulate/run\">
+    <label>Simulate <input type=\"number\" name=\"years\" min=\"1\" value=\"10\"> years</label>
+    <label>or until the year <input type=\"number\" name=\"target\" min=\"{}\"></label>
+    <label>& stop, if the population drops below <input type=\"number\" name=\"min_population\" min=\"0\"></label>
+    <label>or rises above <input type=\"number\" name=\"max_population\" min=\"0\"></label>
+    <input type=\"submit\" value=\"Simulate\">
+  </form>
+  <form action=\"/reroll\" method=\"post\">
+    <label>Simulate the last year again with seed <input type=\"number\" name=\"seed\" min=\"0\" value=\"1\"></label>
+    <input type=\"submit\" value=\"Re-roll\">
+  </form>
+  <h2>History</h2>{}",
         data.date.get_year(),
+        show_inheritance_law(data.inheritance_law),
+        escape(world.get_name(data.town)),
         data.building_manager.get_all().len(),
         data.character_manager.get_all().len(),
+        show_towns(world),
+        show_running(progress),
+        data.date.get_year() + 1,
+        show_history(history),
     ))
 }
+
+fn show_inheritance_law(selected: InheritanceLaw) -> String {
+    let options: Vec<(&str, &str)> = InheritanceLaw::ALL
+        .iter()
+        .map(|law| (law.get_name(), law.get_name()))
+        .collect();
+
+    show_select("law", &options, Some(selected.get_name()))
+}
+
+pub fn visualize_error(error: &str, back: &str) -> String {
+    html(format!(
+        "
+  <h1>Error</h1>
+  <p>{}</p>
+  <p><a href=\"{}\">Back</a></p>",
+        escape(error),
+        back,
+    ))
+}
+
+/// Refreshes itself, while the simulation is running.
+pub fn visualize_progress(progress: &Progress) -> String {
+    if progress.is_running {
+        html(format!(
+            "
+  <meta http-equiv=\"refresh\" content=\"1\">
+  <h1>Simulating</h1>
+  <p><b>Year:</b> {} of {}</p>
+  <p><progress value=\"{}\" max=\"{}\"></progress></p>
+  <p><a href=\"/simulate/stop\">Stop</a></p>
+  <p><a href=\"/\">Back</a></p>",
+            progress.current_year,
+            progress.target_year,
+            progress.get_simulated_years(),
+            progress.get_total_years(),
+        ))
+    } else {
+        html(format!(
+            "
+  <h1>Simulation finished</h1>
+  <p>{}</p>
+  <p><b>Simulated Years:</b> {}</p>
+  <p><a href=\"/\">Back</a></p>",
+            progress
+                .result
+                .as_deref()
+                .unwrap_or("No simulation was started."),
+            progress.get_simulated_years(),
+        ))
+    }
+}
+
+fn show_running(progress: &Progress) -> String {
+    if progress.is_running {
+        format!(
+            "<p><b>Simulating until {}:</b> <a href=\"/simulate/progress\">{}</a></p>",
+            progress.target_year, progress.current_year
+        )
+    } else {
+        String::new()
+    }
+}
+
+fn show_towns(world: &World) -> String {
+    let vector: Vec<String> = world
+        .get_town_ids()
+        .into_iter()
+        .map(|town| {
+            format!(
+                "   <li>{} (Residents: {}, Size: {}x{})</li>",
+                show_town_link(world, town),
+                world.get_residents(town).len(),
+                world.get_map(town).width(),
+                world.get_map(town).height(),
+            )
+        })
+        .collect();
+
+    vector.join("\n")
+}
+
+/// Links to the selection of the town, unless it is already selected.
+pub fn show_town_link(world: &World, town: TownId) -> String {
+    if town == world.get_selected_town() {
+        format!("<b>{}</b>", escape(world.get_name(town)))
+    } else {
+        format!(
+            "<a href=\"/town/select/{}\">{}</a>",
+            town.id(),
+            escape(world.get_name(town))
+        )
+    }
+}
+
+fn show_history(years: &[u32]) -> String {
+    if years.is_empty() {
+        return "\n  <p>No years were simulated yet.</p>".to_string();
+    }
+
+    let years: Vec<String> = years.iter().map(|year| year.to_string()).collect();
+    let options: Vec<(&str, &str)> = years
+        .iter()
+        .map(|year| (year.as_str(), year.as_str()))
+        .collect();
+
+    format!(
+        "
+  <form action=\"/rewind\" method=\"post\">
+    <label>Rewind to the start of {}</label>
+    <input type=\"submit\" value=\"Rewind\">
+  </form>",
+        show_select("year", &options, years.last().map(|year| year.as_str())),
+    )
+}
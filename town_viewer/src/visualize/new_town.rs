@@ -0,0 +1,54 @@
+use crate::init::TownSettings;
+use crate::visualize::{escape, html};
+
+/// The form of the new-town wizard, which shows the error of the last attempt.
+pub fn visualize_new_town(
+    settings: &TownSettings,
+    cultures: &[String],
+    error: Option<&str>,
+) -> String {
+    html(format!(
+        "
+  <h1>New Town</h1>
+  {}
+  <form action=\"/new\" method=\"post\">
+    <p><label>Name <input type=\"text\" name=\"name\" value=\"{}\" required></label></p>
+    <p><label>Seed <input type=\"number\" name=\"seed\" min=\"0\" value=\"{}\"></label></p>
+    <p><label>Start Year <input type=\"number\" name=\"start_year\" min=\"20\" value=\"{}\"></label></p>
+    <p><label>Founders <input type=\"number\" name=\"founders\" min=\"5\" max=\"1000\" value=\"{}\"></label></p>
+    <p><label>Warm-up Years <input type=\"number\" name=\"warm_up_years\" min=\"0\" max=\"1000\" value=\"{}\"></label></p>
+    <p><label>Map Size <input type=\"number\" name=\"size\" min=\"1\" max=\"20\" value=\"{}\"> blocks</label></p>
+    <p><label>Villages <input type=\"number\" name=\"villages\" min=\"0\" max=\"5\" value=\"{}\"></label></p>
+    <p><label>Name Culture <select name=\"culture\">{}</select></label></p>
+    <p><input type=\"submit\" value=\"Create\"></p>
+  </form>
+  <p>The current town & its history are replaced.</p>
+  <p><a href=\"/\">Back</a></p>",
+        error
+            .map(|error| format!("<p><b>Error:</b> {}</p>", escape(error)))
+            .unwrap_or_default(),
+        escape(&settings.name),
+        settings.seed,
+        settings.start_year,
+        settings.founders,
+        settings.warm_up_years,
+        settings.size,
+        settings.villages,
+        show_cultures(cultures, &settings.culture),
+    ))
+}
+
+fn show_cultures(cultures: &[String], selected: &str) -> String {
+    let options: Vec<String> = cultures
+        .iter()
+        .map(|culture| {
+            format!(
+                "<option value=\"{0}\"{1}>{0}</option>",
+                escape(culture),
+                if culture == selected { " selected" } else { "" }
+            )
+        })
+        .collect();
+
+    options.join("")
+}
@@ -0,0 +1,76 @@
+use crate::visualize::chart::{render_bar_chart, render_line_chart, render_pyramid, Series};
+use crate::visualize::html;
+use town_simulation::statistics::{
+    get_average_lifespan, get_household_sizes, get_population_pyramid, YearStatistics,
+};
+use town_simulation::world::World;
+
+const AGE_GROUP_SIZE: u32 = 5;
+
+pub fn visualize_statistics(world: &World, statistics: &[YearStatistics]) -> String {
+    let years: Vec<u32> = statistics.iter().map(|year| *year.year()).collect();
+    let get_values = |get: fn(&YearStatistics) -> usize| statistics.iter().map(get).collect();
+    let household_sizes: Vec<(String, usize)> = get_household_sizes(world)
+        .into_iter()
+        .map(|(size, homes)| (size.to_string(), homes))
+        .collect();
+
+    html(format!(
+        "
+  <h1>Statistics</h1>
+  <p><b>Average Lifespan:</b> {}</p>
+  <p><a href=\"/stats/yearly.csv\" download=\"statistics.csv\">Export CSV</a></p>
+  <h2>Population Pyramid</h2>
+  <p>Living residents of all towns in age groups of {} years</p>
+  {}
+  <h2>Population & Buildings</h2>
+  {}
+  <h2>Births, Deaths & Marriages</h2>
+  {}
+  <h2>Household Sizes</h2>
+  <p>Homes by number of occupants</p>
+  {}
+  <p><a href=\"/\">Back</a></p>",
+        get_average_lifespan(world)
+            .map(|lifespan| format!("{:.1} years", lifespan))
+            .unwrap_or_else(|| "-".to_string()),
+        AGE_GROUP_SIZE,
+        render_pyramid(&get_population_pyramid(world, AGE_GROUP_SIZE)),
+        render_line_chart(
+            &years,
+            &[
+                Series {
+                    name: "Population",
+                    color: "blue",
+                    values: get_values(|year| *year.population()),
+                },
+                Series {
+                    name: "Buildings",
+                    color: "brown",
+                    values: get_values(|year| *year.buildings()),
+                },
+            ],
+        ),
+        render_line_chart(
+            &years,
+            &[
+                Series {
+                    name: "Births",
+                    color: "green",
+                    values: get_values(|year| *year.births()),
+                },
+                Series {
+                    name: "Deaths",
+                    color: "black",
+                    values: get_values(|year| *year.deaths()),
+                },
+                Series {
+                    name: "Marriages",
+                    color: "red",
+                    values: get_values(|year| *year.marriages()),
+                },
+            ],
+        ),
+        render_bar_chart(&household_sizes, "orange"),
+    ))
+}
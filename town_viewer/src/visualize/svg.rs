@@ -0,0 +1,331 @@
+use crate::visualize::building::show_address;
+use crate::visualize::escape;
+use town_simulation::model::building::owner::Owner;
+use town_simulation::model::building::usage::BuildingUsage;
+use town_simulation::model::building::Building;
+use town_simulation::model::town::district::District;
+use town_simulation::model::town::map::{LotSize, TownBlock, TownLot};
+use town_simulation::usecase::building::occupancy::{get_average_age_of_occupants, get_occupants};
+use town_simulation::SimulationData;
+
+const BLOCK_SIZE: usize = 100;
+const STREET_WIDTH: usize = 20;
+const LOT_SIZE: usize = BLOCK_SIZE / 2;
+const BUILDING_MARGIN: usize = 6;
+const MAX_AGE: u32 = 100;
+const MAX_OCCUPANTS_PER_LOT: usize = 8;
+const NO_DATA_COLOR: &str = "#888888";
+const LOW_COLOR: (u8, u8, u8) = (255, 255, 178);
+const HIGH_COLOR: (u8, u8, u8) = (189, 0, 38);
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum MapColoring {
+    #[default]
+    Usage,
+    Age,
+    Owner,
+    /// Colours the buildings by the last name of their owner.
+    Family,
+    /// Heatmap of the occupants per lot.
+    Occupants,
+    OccupantAge,
+    Vacancy,
+}
+
+impl MapColoring {
+    pub const ALL: [MapColoring; 7] = [
+        MapColoring::Usage,
+        MapColoring::Age,
+        MapColoring::Owner,
+        MapColoring::Family,
+        MapColoring::Occupants,
+        MapColoring::OccupantAge,
+        MapColoring::Vacancy,
+    ];
+
+    pub fn parse(name: Option<&str>) -> Self {
+        MapColoring::ALL
+            .into_iter()
+            .find(|coloring| Some(coloring.get_name()) == name)
+            .unwrap_or_default()
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            MapColoring::Usage => "usage",
+            MapColoring::Age => "age",
+            MapColoring::Owner => "owner",
+            MapColoring::Family => "family",
+            MapColoring::Occupants => "occupants",
+            MapColoring::OccupantAge => "occupant_age",
+            MapColoring::Vacancy => "vacancy",
+        }
+    }
+
+    pub fn get_legend(&self) -> Option<String> {
+        let (text, max) = match self {
+            MapColoring::Age => ("Building age in years", format!("{}+", MAX_AGE)),
+            MapColoring::Occupants => ("Occupants per lot", format!("{}+", MAX_OCCUPANTS_PER_LOT)),
+            MapColoring::OccupantAge => ("Average age of the occupants", format!("{}+", MAX_AGE)),
+            MapColoring::Vacancy => ("Vacant homes", "100%".to_string()),
+            _ => return None,
+        };
+
+        Some(format!(
+            "{}: <span style=\"background-color: {}\">0</span> to <span style=\"background-color: {}\">{}</span> (grey = no data)",
+            text,
+            interpolate_color(LOW_COLOR, HIGH_COLOR, 0.0),
+            interpolate_color(LOW_COLOR, HIGH_COLOR, 1.0),
+            max,
+        ))
+    }
+}
+
+/// Renders the blocks, streets & buildings of the town as a standalone svg image.
+pub fn render_map(data: &SimulationData, coloring: MapColoring) -> String {
+    let width = get_offset(*data.map.width());
+    let height = get_offset(*data.map.height());
+    let mut elements = Vec::new();
+
+    for row in 0..*data.map.height() {
+        for column in 0..*data.map.width() {
+            elements.push(render_block(data, coloring, row, column));
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">
+ <rect width=\"{0}\" height=\"{1}\" fill=\"#bbbbbb\"/>
+{2}
+{3}
+</svg>",
+        width,
+        height,
+        render_street_names(data),
+        elements.join("\n"),
+    )
+}
+
+/// Returns the position of the north-western corner of the block in a row or column.
+fn get_offset(index: usize) -> usize {
+    STREET_WIDTH + index * (BLOCK_SIZE + STREET_WIDTH)
+}
+
+fn render_street_names(data: &SimulationData) -> String {
+    let horizontal = data
+        .map
+        .horizontal_streets()
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let y = get_offset(index) - STREET_WIDTH / 2;
+            format!(
+                " <text x=\"{}\" y=\"{}\" font-size=\"10\" dominant-baseline=\"middle\">{}</text>",
+                STREET_WIDTH,
+                y,
+                escape(name)
+            )
+        });
+    let vertical = data
+        .map
+        .vertical_streets()
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let x = get_offset(index) - STREET_WIDTH / 2;
+            format!(
+                " <text x=\"{0}\" y=\"{1}\" font-size=\"10\" dominant-baseline=\"middle\" transform=\"rotate(90 {0} {1})\">{2}</text>",
+                x,
+                STREET_WIDTH,
+                escape(name)
+            )
+        });
+
+    horizontal.chain(vertical).collect::<Vec<_>>().join("\n")
+}
+
+fn render_block(data: &SimulationData, coloring: MapColoring, row: usize, column: usize) -> String {
+    let index = data.map.get_index(column, row);
+    let block = data.map.get_block(row, column);
+    let district = data.map.get_district(index);
+    let (x, y) = (get_offset(column), get_offset(row));
+    let title = match block {
+        TownBlock::EmptyBlock | TownBlock::SmallBuildings(_) | TownBlock::LargeBuilding(_) => {
+            format!(
+                "{:?} (Land Value: {})",
+                district,
+                data.map.get_land_value(index)
+            )
+        }
+        _ => format!("{:?}", block),
+    };
+    let mut elements = vec![format!(
+        " <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"><title>{}</title></rect>",
+        x,
+        y,
+        BLOCK_SIZE,
+        BLOCK_SIZE,
+        get_block_color(block, district),
+        title,
+    )];
+
+    match block {
+        TownBlock::SmallBuildings(lots) => {
+            let mut rendered: Vec<TownLot> = Vec::new();
+
+            for (lot, town_lot) in lots.iter().enumerate() {
+                if *town_lot != TownLot::EmptyLot && !rendered.contains(town_lot) {
+                    rendered.push(*town_lot);
+                    elements.push(render_town_lot(data, coloring, town_lot, x, y, lot));
+                }
+            }
+        }
+        TownBlock::LargeBuilding(town_lot) => {
+            elements.push(render_town_lot(data, coloring, town_lot, x, y, 0))
+        }
+        _ => {}
+    }
+
+    elements.join("\n")
+}
+
+fn get_block_color(block: &TownBlock, district: District) -> &'static str {
+    match block {
+        TownBlock::Park => "#93c47d",
+        TownBlock::Farmland => "#f3e9b5",
+        TownBlock::Forest => "#6aa84f",
+        TownBlock::Hills => "#b4a7d6",
+        TownBlock::Mill => "#c9a66b",
+        TownBlock::Water => "#9fc5e8",
+        _ => match district {
+            District::Centre => "#f4c7c3",
+            District::Market => "#fce8b2",
+            District::Residential => "#d9ead3",
+            District::Outskirts => "#e6efd9",
+            District::Farmland => "#f3efd9",
+        },
+    }
+}
+
+fn render_town_lot(
+    data: &SimulationData,
+    coloring: MapColoring,
+    town_lot: &TownLot,
+    block_x: usize,
+    block_y: usize,
+    lot: usize,
+) -> String {
+    let (id, is_construction_site) = match town_lot {
+        TownLot::ConstructionSite(id) => (*id, true),
+        TownLot::BuildingLot(id) => (*id, false),
+        TownLot::EmptyLot => return "".to_string(),
+    };
+    let building = data.building_manager.get(id).unwrap();
+    let lots = match building.usage().get_lot_size() {
+        LotSize::Single => (1, 1),
+        LotSize::Double => (2, 1),
+        LotSize::Block => (2, 2),
+    };
+    let x = block_x + (lot % 2) * LOT_SIZE + BUILDING_MARGIN;
+    let y = block_y + (lot / 2) * LOT_SIZE + BUILDING_MARGIN;
+    let style = if is_construction_site {
+        " stroke=\"black\" stroke-dasharray=\"4\" fill-opacity=\"0.4\""
+    } else {
+        " stroke=\"black\""
+    };
+
+    format!(
+        " <a href=\"/building/{}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"{}><title>{} {}: {}</title></rect></a>",
+        id.id(),
+        x,
+        y,
+        lots.0 * LOT_SIZE - 2 * BUILDING_MARGIN,
+        lots.1 * LOT_SIZE - 2 * BUILDING_MARGIN,
+        get_building_color(data, coloring, building),
+        style,
+        building.usage(),
+        id.id(),
+        show_address(data, id),
+    )
+}
+
+fn get_building_color(data: &SimulationData, coloring: MapColoring, building: &Building) -> String {
+    match coloring {
+        MapColoring::Usage => match building.usage() {
+            BuildingUsage::Apartments(_) => "#4f81bd",
+            BuildingUsage::House(_) => "#c0504d",
+            BuildingUsage::MarketSquare => "#f79646",
+            BuildingUsage::Temple => "#8064a2",
+        }
+        .to_string(),
+        MapColoring::Age => get_heat_color(building.get_age(data.date) as f32, MAX_AGE as f32),
+        MapColoring::Owner => get_owner_color(building.owner()),
+        MapColoring::Family => get_family_color(data, building.owner()),
+        MapColoring::Occupants => {
+            let occupants = get_occupants(&data.building_manager, *building.id()).len();
+            let lots = match building.usage().get_lot_size() {
+                LotSize::Single => 1,
+                LotSize::Double => 2,
+                LotSize::Block => 4,
+            };
+            get_heat_color(occupants as f32 / lots as f32, MAX_OCCUPANTS_PER_LOT as f32)
+        }
+        MapColoring::OccupantAge => get_average_age_of_occupants(data, *building.id())
+            .map(|age| get_heat_color(age as f32, MAX_AGE as f32))
+            .unwrap_or_else(|| NO_DATA_COLOR.to_string()),
+        MapColoring::Vacancy => {
+            let homes = building.usage().get_homes();
+            let vacant = homes.iter().filter(|home| home.is_empty()).count();
+
+            if homes.is_empty() {
+                NO_DATA_COLOR.to_string()
+            } else {
+                get_heat_color(vacant as f32, homes.len() as f32)
+            }
+        }
+    }
+}
+
+fn get_heat_color(value: f32, max: f32) -> String {
+    interpolate_color(LOW_COLOR, HIGH_COLOR, value / max)
+}
+
+/// Each owner gets a different hue, while the town is grey.
+fn get_owner_color(owner: &Owner) -> String {
+    match owner.get_characters().first() {
+        Some(character) => get_hue_color(character.id() * 137),
+        None => NO_DATA_COLOR.to_string(),
+    }
+}
+
+/// Each family gets a different hue based on the last name of the owner.
+fn get_family_color(data: &SimulationData, owner: &Owner) -> String {
+    owner
+        .get_characters()
+        .first()
+        .and_then(|id| data.character_manager.get(*id))
+        .and_then(|character| character.name().get_last())
+        .map(|last| {
+            get_hue_color(
+                last.bytes()
+                    .fold(0, |hash, b| (hash * 31 + b as usize) % 360),
+            )
+        })
+        .unwrap_or_else(|| NO_DATA_COLOR.to_string())
+}
+
+fn get_hue_color(value: usize) -> String {
+    format!("hsl({}, 70%, 55%)", value % 360)
+}
+
+pub fn interpolate_color(from: (u8, u8, u8), to: (u8, u8, u8), ratio: f32) -> String {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * ratio).round() as u8;
+
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        mix(from.0, to.0),
+        mix(from.1, to.1),
+        mix(from.2, to.2)
+    )
+}